@@ -1,10 +1,58 @@
 use super::{Color, Profession};
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 /// Describes a piece on the board.
 /// ／盤上に存在できる駒を表現する。
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+///
+/// In human-readable formats, every piece is serialized as an object tagged with `"type"`:
+/// `{ "type": "Tam2" }` or `{ "type": "NonTam2", "color": ..., "prof": ..., "side": ... }`.
+/// ／人間が読むための形式では、どの駒も `"type"` でタグ付けされたオブジェクトとしてシリアライズされる：
+/// `{ "type": "Tam2" }` または `{ "type": "NonTam2", "color": ..., "prof": ..., "side": ... }`。
+/// Compact formats keep the externally tagged encoding derived from the enum itself.
+/// ／コンパクトな形式では、列挙型から導出される外部タグ形式のままである。
+///
+/// Deserialization also accepts the flat form `{ "color": ..., "prof": ..., "side": ... }`,
+/// where any spelling accepted by `Color` and `Profession` can be used and `side` can also be `"A"` or `"IA"`,
+/// as well as the older externally tagged form, in which Tam2 was the bare string `"Tam2"`.
+/// ／デシリアライズは `{ "color": ..., "prof": ..., "side": ... }` という平らな形式も受け付ける。
+/// そこでは `Color` と `Profession` が受け付けるどの綴りも使え、`side` には `"A"` や `"IA"` も使える。
+/// また、皇が単なる文字列 `"Tam2"` であった以前の外部タグ形式も受け付ける。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let rook = Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Gua2, side: Side::ASide };
+/// assert_eq!(serde_json::to_string(&Piece::Tam2).unwrap(), r#"{"type":"Tam2"}"#);
+/// assert_eq!(
+///     serde_json::to_string(&rook).unwrap(),
+///     r#"{"type":"NonTam2","color":"赤","prof":"弓","side":"ASide"}"#
+/// );
+///
+/// let json = serde_json::to_string(&rook).unwrap();
+/// assert_eq!(serde_json::from_str::<Piece>(&json).unwrap(), rook);
+/// assert_eq!(serde_json::from_str::<Piece>(r#"{"type":"Tam2"}"#).unwrap(), Piece::Tam2);
+///
+/// // the flat form and the older form are accepted as well
+/// assert_eq!(
+///     serde_json::from_str::<Piece>(r#"{"color":"red","prof":"rook","side":"A"}"#).unwrap(),
+///     rook
+/// );
+/// assert_eq!(serde_json::from_str::<Piece>(r#""Tam2""#).unwrap(), Piece::Tam2);
+/// assert_eq!(
+///     serde_json::from_str::<Piece>(r#"{"NonTam2Piece":{"color":"赤","prof":"弓","side":"ASide"}}"#).unwrap(),
+///     rook
+/// );
+///
+/// // `NonTam2Piece` takes any spelling as well, ignoring `side`
+/// assert_eq!(
+///     serde_json::from_str::<NonTam2Piece>(r#"{"color":"red","prof":"rook","side":"A"}"#).unwrap(),
+///     NonTam2Piece { color: Color::Kok1, prof: Profession::Gua2 }
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum Piece {
     /// Tam2, a special piece belonging to both sides. Both players can move it.
     /// ／皇（たむ）。自分も相手も動かすことができる共有の駒である。
@@ -24,6 +72,104 @@ pub enum Piece {
     },
 }
 
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "Piece")]
+enum TaggedPiece {
+    Tam2,
+    NonTam2Piece {
+        color: Color,
+        prof: Profession,
+        side: Side,
+    },
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "type")]
+enum UniformPiece {
+    Tam2,
+    NonTam2 {
+        color: Color,
+        prof: Profession,
+        side: Side,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HumanReadablePiece {
+    Uniform(UniformPiece),
+    Tagged(TaggedPiece),
+    Flat {
+        color: Color,
+        prof: Profession,
+        #[serde(deserialize_with = "deserialize_side_leniently")]
+        side: Side,
+    },
+}
+
+fn deserialize_side_leniently<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    match s.as_str() {
+        "ASide" => Ok(Side::ASide),
+        "IASide" => Ok(Side::IASide),
+        _ => Side::from_str(&s).map_err(serde::de::Error::custom),
+    }
+}
+
+impl From<TaggedPiece> for Piece {
+    fn from(piece: TaggedPiece) -> Self {
+        match piece {
+            TaggedPiece::Tam2 => Piece::Tam2,
+            TaggedPiece::NonTam2Piece { color, prof, side } => {
+                Piece::NonTam2Piece { color, prof, side }
+            }
+        }
+    }
+}
+
+impl Serialize for Piece {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        match *self {
+            Piece::Tam2 if serializer.is_human_readable() => {
+                UniformPiece::Tam2.serialize(serializer)
+            }
+            Piece::Tam2 => TaggedPiece::Tam2.serialize(serializer),
+            Piece::NonTam2Piece { color, prof, side } if serializer.is_human_readable() => {
+                UniformPiece::NonTam2 { color, prof, side }.serialize(serializer)
+            }
+            Piece::NonTam2Piece { color, prof, side } => {
+                TaggedPiece::NonTam2Piece { color, prof, side }.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Piece {
+    fn deserialize<D>(deserializer: D) -> Result<Piece, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            Ok(match HumanReadablePiece::deserialize(deserializer)? {
+                HumanReadablePiece::Uniform(UniformPiece::Tam2) => Piece::Tam2,
+                HumanReadablePiece::Tagged(piece) => piece.into(),
+                HumanReadablePiece::Uniform(UniformPiece::NonTam2 { color, prof, side })
+                | HumanReadablePiece::Flat { color, prof, side } => {
+                    Piece::NonTam2Piece { color, prof, side }
+                }
+            })
+        } else {
+            TaggedPiece::deserialize(deserializer).map(Piece::from)
+        }
+    }
+}
+
 /// Calculates the distance between two points.
 /// The distance is defined as the larger of the difference between either the x or y coordinates.
 /// ／2点間の距離（x座標の差およびy座標の差のうち小さくない方）を計算する。
@@ -92,6 +238,66 @@ impl Piece {
     }
 }
 
+impl std::fmt::Display for Piece {
+    /// Displays the piece as color, profession and the side (`A` or `IA`); Tam2 is displayed as `皇`.
+    /// ／駒を、色・職種・所属側（`A` または `IA`）の順で表示する。皇は `皇` と表示する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// assert_eq!(Piece::Tam2.to_string(), "皇");
+    /// assert_eq!(Piece::NonTam2Piece {
+    ///     prof: Profession::Uai1,
+    ///     color: Color::Kok1,
+    ///     side: Side::IASide
+    /// }.to_string(), "赤将IA");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Piece::Tam2 => write!(f, "皇"),
+            Piece::NonTam2Piece { color, prof, side } => write!(
+                f,
+                "{}{}{}",
+                super::serialize_color(color),
+                super::serialize_prof(prof),
+                side
+            ),
+        }
+    }
+}
+
+impl PartialOrd for Piece {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Piece {
+    /// Orders `Tam2` first, and then `NonTam2Piece`s by `(side, color, prof)`, each compared in its declaration order.
+    /// Unlike [`NonTam2Piece`](./struct.NonTam2Piece.html), whose order groups a hop1zuo1 by profession, the color comes before the profession.
+    /// ／`Tam2` を先頭とし、続いて `NonTam2Piece` を `(side, color, prof)` の順で、それぞれ宣言順で比較する。
+    /// 手駒を職種ごとにまとめる [`NonTam2Piece`](./struct.NonTam2Piece.html) の順序とは異なり、色を職種より先に比較する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let a_io = Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Io, side: Side::ASide };
+    /// let ia_nuak = Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Nuak1, side: Side::IASide };
+    /// let mut pieces = vec![ia_nuak, a_io, Piece::Tam2];
+    /// pieces.sort();
+    /// assert_eq!(pieces, vec![Piece::Tam2, a_io, ia_nuak]);
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let key = |piece: &Piece| match *piece {
+            Piece::Tam2 => None,
+            Piece::NonTam2Piece { color, prof, side } => Some((side, color, prof)),
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
 /// Checks if the square is a tam2 nua2 (tam2's water), entry to which is restricted.
 /// ／マスが皇水（たむぬあ）であるかどうかの判定
 #[must_use]
@@ -116,6 +322,30 @@ pub struct NonTam2Piece {
     pub prof: Profession,
 }
 
+impl NonTam2Piece {
+    /// Turns into a `Piece` on the board that belongs to the given side, as when it is dropped from a hop1zuo1.
+    /// ／指定の陣営に属する盤上の `Piece` にする。手駒を打つときなどに用いる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    /// use cetkaik_core::*;
+    ///
+    /// let piece = NonTam2Piece { color: Color::Huok2, prof: Profession::Gua2 };
+    /// assert_eq!(
+    ///     piece.with_side(Side::ASide),
+    ///     Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Gua2, side: Side::ASide }
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_side(self, side: Side) -> Piece {
+        Piece::NonTam2Piece {
+            color: self.color,
+            prof: self.prof,
+            side,
+        }
+    }
+}
+
 impl std::fmt::Display for NonTam2Piece {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -126,11 +356,94 @@ impl std::fmt::Display for NonTam2Piece {
         )
     }
 }
+/// Describes an error that occurred while parsing a `NonTam2Piece`. Contains the input that failed to parse.
+/// ／`NonTam2Piece` のパースに失敗したことを表す。失敗した入力を保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseNonTam2PieceError(pub String);
+
+impl std::fmt::Display for ParseNonTam2PieceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid non-Tam2 piece: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseNonTam2PieceError {}
+
+impl PartialOrd for NonTam2Piece {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonTam2Piece {
+    /// Orders by `(prof, color)`, each compared in its declaration order, so that pieces of the same profession come together.
+    /// Note that this differs from [`Piece`](./enum.Piece.html), which compares the color before the profession.
+    /// ／`(prof, color)` の順で、それぞれ宣言順で比較する。同じ職種の駒がまとまるようにするためである。
+    /// 色を職種より先に比較する [`Piece`](./enum.Piece.html) とは異なることに注意。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let black_pawn = NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 };
+    /// let red_king = NonTam2Piece { color: Color::Kok1, prof: Profession::Io };
+    /// assert!(black_pawn < red_king);
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.prof, self.color).cmp(&(other.prof, other.color))
+    }
+}
+
+/// Sorts a hop1zuo1 by profession and then by color, so that it can be displayed in a stable order regardless of the order of capture.
+/// ／手駒を職種、続いて色の順に整列する。取った順番によらず、安定した順で表示できるようにするためである。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let mut hop1zuo1 = vec![
+///     NonTam2Piece { color: Color::Huok2, prof: Profession::Io },
+///     NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 },
+///     NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 },
+/// ];
+/// sort_hop1zuo1(&mut hop1zuo1);
+/// assert_eq!(hop1zuo1, vec![
+///     NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 },
+///     NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 },
+///     NonTam2Piece { color: Color::Huok2, prof: Profession::Io },
+/// ]);
+/// ```
+pub fn sort_hop1zuo1(hop1zuo1: &mut [NonTam2Piece]) {
+    hop1zuo1.sort_unstable();
+}
+
 use std::convert::TryInto;
 impl TryInto<NonTam2Piece> for &str {
-    type Error = ();
+    type Error = ParseNonTam2PieceError;
     fn try_into(self) -> Result<NonTam2Piece, Self::Error> {
-        Ok(match self {
+        NonTam2Piece::from_str(self)
+    }
+}
+
+impl FromStr for NonTam2Piece {
+    type Err = ParseNonTam2PieceError;
+
+    /// Parses the color followed by the profession, as printed by `Display`.
+    /// ／`Display` が出力するような、色と職種を並べた文字列を読む。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    /// use std::convert::TryInto;
+    ///
+    /// let piece: NonTam2Piece = "赤将".parse().unwrap();
+    /// assert_eq!(piece, NonTam2Piece { color: Color::Kok1, prof: Profession::Uai1 });
+    ///
+    /// let bad: Result<NonTam2Piece, _> = "赤皇".try_into();
+    /// assert_eq!(bad, Err(ParseNonTam2PieceError("赤皇".to_string())));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
             "黒兵" => NonTam2Piece {
                 color: Color::Huok2,
                 prof: Profession::Kauk2,
@@ -211,31 +524,457 @@ impl TryInto<NonTam2Piece> for &str {
                 color: Color::Kok1,
                 prof: Profession::Nuak1,
             },
-            _ => return Err(()),
+            _ => return Err(ParseNonTam2PieceError(s.to_string())),
         })
     }
 }
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Describes the board, the 9x9 squares, in terms of absolute coordinates.
 /// ／盤、つまり、9x9のマス目を、絶対座標で表す。
 pub type Board = HashMap<Coord, Piece>;
 
+/// Builds a board out of pairs of a coordinate and a piece. If a coordinate appears more than once, the last one wins.
+/// ／座標と駒の組から盤を作る。同じ座標が複数回現れたら、最後のものが採用される。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let board = board_from_iter(vec![
+///     (Coord(Row::O, Column::Z), Piece::Tam2),
+///     (
+///         Coord(Row::A, Column::Z),
+///         Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Io, side: Side::ASide },
+///     ),
+/// ]);
+/// assert_eq!(board.len(), 2);
+/// assert_eq!(board.get(&Coord(Row::O, Column::Z)), Some(&Piece::Tam2));
+/// ```
+pub fn board_from_iter(iter: impl IntoIterator<Item = (Coord, Piece)>) -> Board {
+    iter.into_iter().collect()
+}
+
+/// Lists every square whose content differs between two boards, as `(coord, before, after)`.
+/// ／二つの盤の間で中身が異なるマスを、`(座標, 変化前, 変化後)` の形で全て列挙する。
+///
+/// The result is sorted in row-major order, from `KA` to `PIA`.
+/// ／結果は `KA` から `PIA` まで行優先で並ぶ。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let old = yhuap_initial_board();
+/// let mut new = old.clone();
+/// let vessel = new.remove(&Coord(Row::AI, Column::Z)).unwrap();
+/// new.insert(Coord(Row::Y, Column::Z), vessel);
+/// assert_eq!(
+///     board_diff(&old, &new),
+///     vec![
+///         (Coord(Row::Y, Column::Z), None, Some(vessel)),
+///         (Coord(Row::AI, Column::Z), Some(vessel), None),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn board_diff(old: &Board, new: &Board) -> Vec<(Coord, Option<Piece>, Option<Piece>)> {
+    let mut coords: Vec<Coord> = old
+        .keys()
+        .chain(new.keys().filter(|coord| !old.contains_key(coord)))
+        .copied()
+        .filter(|coord| old.get(coord) != new.get(coord))
+        .collect();
+    coords.sort();
+    coords
+        .into_iter()
+        .map(|coord| (coord, old.get(&coord).copied(), new.get(&coord).copied()))
+        .collect()
+}
+
+/// Describes a mismatch found while applying a diff: the square did not hold what the diff expected.
+/// ／差分の適用時に見つかった食い違いを表す。マスの中身が差分の想定と異なっていた。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct DiffError {
+    /// the square at which the mismatch was found／食い違いが見つかったマス
+    pub coord: Coord,
+
+    /// what the diff expected to be there／差分が想定していた中身
+    pub expected: Option<Piece>,
+
+    /// what was actually there／実際の中身
+    pub found: Option<Piece>,
+}
+
+impl std::fmt::Display for DiffError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |p: Option<Piece>| p.map_or_else(|| "nothing".to_string(), |p| p.to_string());
+        write!(
+            f,
+            "expected {} at {}, but found {}",
+            show(self.expected),
+            serialize_coord(self.coord),
+            show(self.found)
+        )
+    }
+}
+
+impl std::error::Error for DiffError {}
+
+/// Applies a diff computed by [`board_diff`](./fn.board_diff.html).
+/// ／[`board_diff`](./fn.board_diff.html) で得られた差分を盤に適用する。
+///
+/// Every `before` is checked against the current board first; if any of them does not match,
+/// the board is left untouched and an error is returned.
+/// ／まず全ての `before` が現在の盤と一致するかを確かめる。一つでも一致しなければ、盤には手を付けずにエラーを返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let old = yhuap_initial_board();
+/// let mut new = old.clone();
+/// let vessel = new.remove(&Coord(Row::AI, Column::Z)).unwrap();
+/// new.insert(Coord(Row::Y, Column::Z), vessel);
+/// let diff = board_diff(&old, &new);
+///
+/// let mut board = old.clone();
+/// apply_diff(&mut board, &diff).unwrap();
+/// assert_eq!(board, new);
+///
+/// // the diff is now stale
+/// assert_eq!(
+///     apply_diff(&mut board, &diff),
+///     Err(DiffError { coord: Coord(Row::Y, Column::Z), expected: None, found: Some(vessel) })
+/// );
+/// assert_eq!(board, new);
+/// ```
+///
+/// # Errors
+/// Returns the first mismatch found, if any.
+/// ／食い違いがあれば、最初に見つかったものを返す。
+pub fn apply_diff(
+    board: &mut Board,
+    diff: &[(Coord, Option<Piece>, Option<Piece>)],
+) -> Result<(), DiffError> {
+    for &(coord, before, _) in diff {
+        let found = board.get(&coord).copied();
+        if found != before {
+            return Err(DiffError {
+                coord,
+                expected: before,
+                found,
+            });
+        }
+    }
+    for &(coord, _, after) in diff {
+        match after {
+            Some(piece) => board.insert(coord, piece),
+            None => board.remove(&coord),
+        };
+    }
+    Ok(())
+}
+
+/// Describes the board with a `BTreeMap`, so that iteration, serialization and hashing are deterministic.
+/// ／盤を `BTreeMap` で表す。列挙・シリアライズ・ハッシュが決定的になる。
+///
+/// Convert from and to [`Board`](./type.Board.html) with [`to_ordered_board`](./fn.to_ordered_board.html)
+/// and [`from_ordered_board`](./fn.from_ordered_board.html).
+/// ／[`Board`](./type.Board.html) との相互変換には [`to_ordered_board`](./fn.to_ordered_board.html) と
+/// [`from_ordered_board`](./fn.from_ordered_board.html) を用いる。
+pub type OrderedBoard = std::collections::BTreeMap<Coord, Piece>;
+
+/// Converts [`Board`](./type.Board.html) into [`OrderedBoard`](./type.OrderedBoard.html).
+/// ／[`Board`](./type.Board.html) を [`OrderedBoard`](./type.OrderedBoard.html) に変換する。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let board = yhuap_initial_board();
+/// let ordered = to_ordered_board(&board);
+/// assert_eq!(ordered.keys().next(), Some(&Coord(Row::A, Column::K)));
+/// assert_eq!(from_ordered_board(&ordered), board);
+/// ```
+#[must_use]
+pub fn to_ordered_board(board: &Board) -> OrderedBoard {
+    board
+        .iter()
+        .map(|(&coord, &piece)| (coord, piece))
+        .collect()
+}
+
+/// Converts [`OrderedBoard`](./type.OrderedBoard.html) into [`Board`](./type.Board.html).
+/// ／[`OrderedBoard`](./type.OrderedBoard.html) を [`Board`](./type.Board.html) に変換する。
+#[must_use]
+pub fn from_ordered_board(board: &OrderedBoard) -> Board {
+    board
+        .iter()
+        .map(|(&coord, &piece)| (coord, piece))
+        .collect()
+}
+
+/// Checks whether two boards become identical when red and black are swapped in one of them.
+/// ／一方の盤の赤と黒を入れ替えると、もう一方の盤と一致するかどうかを調べる。
+///
+/// Coordinates and sides are left unchanged, and Tam2 is compared as is.
+/// ／座標と所属側はそのままで、皇はそのまま比較する。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let a = yhuap_initial_board();
+/// let b: Board = a
+///     .iter()
+///     .map(|(&coord, &piece)| match piece {
+///         Piece::Tam2 => (coord, piece),
+///         Piece::NonTam2Piece { color, prof, side } => {
+///             (coord, Piece::NonTam2Piece { color: !color, prof, side })
+///         }
+///     })
+///     .collect();
+/// assert!(boards_equal_modulo_color(&a, &b));
+/// assert!(!boards_equal_modulo_color(&a, &a));
+/// ```
+#[must_use]
+pub fn boards_equal_modulo_color(a: &Board, b: &Board) -> bool {
+    a.len() == b.len()
+        && a.iter().all(|(coord, piece)| {
+            let flipped = match *piece {
+                Piece::Tam2 => Piece::Tam2,
+                Piece::NonTam2Piece { color, prof, side } => Piece::NonTam2Piece {
+                    color: !color,
+                    prof,
+                    side,
+                },
+            };
+            b.get(coord) == Some(&flipped)
+        })
+}
+
+/// Lists every square holding a piece of the given profession, regardless of its color or side.
+/// ／指定された職種の駒（色や所属側は問わない）があるマスを全て列挙する。
+///
+/// The result is sorted in row-major order, from `KA` to `PIA`.
+/// ／結果は `KA` から `PIA` まで行優先で並ぶ。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(
+///     find_profession(&yhuap_initial_board(), Profession::Io),
+///     vec![Coord(Row::A, Column::Z), Coord(Row::IA, Column::Z)]
+/// );
+/// ```
+#[must_use]
+pub fn find_profession(board: &Board, prof: Profession) -> Vec<Coord> {
+    let mut ans: Vec<Coord> = board
+        .iter()
+        .filter(|(_, piece)| piece.has_prof(prof))
+        .map(|(&coord, _)| coord)
+        .collect();
+    ans.sort();
+    ans
+}
+
+/// Lists every square holding the piece with the given color, profession and side.
+/// ／指定された色・職種・所属側の駒があるマスを全て列挙する。
+///
+/// The result is sorted in row-major order, from `KA` to `PIA`.
+/// ／結果は `KA` から `PIA` まで行優先で並ぶ。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(
+///     find_piece(&yhuap_initial_board(), Color::Kok1, Profession::Gua2, Side::ASide),
+///     vec![Coord(Row::E, Column::L)]
+/// );
+/// ```
+#[must_use]
+pub fn find_piece(board: &Board, color: Color, prof: Profession, side: Side) -> Vec<Coord> {
+    let target = Piece::NonTam2Piece { color, prof, side };
+    let mut ans: Vec<Coord> = board
+        .iter()
+        .filter(|(_, &piece)| piece == target)
+        .map(|(&coord, _)| coord)
+        .collect();
+    ans.sort();
+    ans
+}
+
+/// Lists the position and the side of every king (`Io`), sorted in row-major order.
+/// ／全ての王の位置と所属側を、行優先で並べて列挙する。
+///
+/// The standard game has exactly two kings, but this function does not assume so and simply returns all of them.
+/// ／通常の対局では王はちょうど二つだが、この関数はそれを仮定せず、見つかった全ての王を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(
+///     find_kings(&yhuap_initial_board()),
+///     vec![(Coord(Row::A, Column::Z), Side::ASide), (Coord(Row::IA, Column::Z), Side::IASide)]
+/// );
+/// ```
+#[must_use]
+pub fn find_kings(board: &Board) -> Vec<(Coord, Side)> {
+    find_profession(board, Profession::Io)
+        .into_iter()
+        .filter_map(|coord| match board.get(&coord) {
+            Some(Piece::NonTam2Piece { side, .. }) => Some((coord, *side)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the position of the king (`Io`) belonging to the side.
+/// ／指定された側の王の位置を返す。
+///
+/// Returns `None` if the side has no king, or more than one king.
+/// ／その側の王が一つもない場合や、複数ある場合は `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert_eq!(king_of(&board, Side::IASide), Some(Coord(Row::IA, Column::Z)));
+///
+/// board.remove(&Coord(Row::IA, Column::Z));
+/// assert_eq!(king_of(&board, Side::IASide), None);
+/// ```
+#[must_use]
+pub fn king_of(board: &Board, side: Side) -> Option<Coord> {
+    let mut kings = find_kings(board)
+        .into_iter()
+        .filter(|&(_, s)| s == side)
+        .map(|(coord, _)| coord);
+    let king = kings.next()?;
+    if kings.next().is_some() {
+        return None;
+    }
+    Some(king)
+}
+
+/// For each side, lists the pieces that the side had in the initial configuration but no longer has on the board.
+/// ／各陣営について、初期配置にはあったが今は盤上にない駒を列挙する。
+///
+/// Returns the multiset difference between the pieces that each side owns in
+/// [`yhuap_initial_board`](./fn.yhuap_initial_board.html) and the pieces that the side owns on `board`,
+/// as a pair of the result for `ASide` and that for `IASide`.
+/// Pieces that are on the board in excess of the initial configuration are simply ignored.
+/// ／[`yhuap_initial_board`](./fn.yhuap_initial_board.html) において各陣営が持つ駒と、`board` 上でその陣営が持つ駒との多重集合としての差を、
+/// `ASide` の分と `IASide` の分の組として返す。初期配置より多く盤上にある駒は単に無視する。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// board.remove(&Coord(Row::A, Column::K));
+/// board.remove(&Coord(Row::I, Column::L));
+/// assert_eq!(
+///     missing_from_full_set(&board),
+///     (
+///         vec![
+///             NonTam2Piece { color: Color::Huok2, prof: Profession::Kua2 },
+///             NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 },
+///         ],
+///         vec![]
+///     )
+/// );
+/// ```
+#[must_use]
+pub fn missing_from_full_set(board: &Board) -> (Vec<NonTam2Piece>, Vec<NonTam2Piece>) {
+    let initial = yhuap_initial_board();
+    let mut coords: Vec<Coord> = initial.keys().copied().collect();
+    coords.sort();
+
+    let missing = |target_side: Side| {
+        let mut ans: Vec<NonTam2Piece> = coords
+            .iter()
+            .filter_map(|coord| match initial[coord] {
+                Piece::NonTam2Piece { color, prof, side } if side == target_side => {
+                    Some(NonTam2Piece { color, prof })
+                }
+                _ => None,
+            })
+            .collect();
+        for piece in board.values() {
+            if let Piece::NonTam2Piece { color, prof, side } = *piece {
+                if side == target_side {
+                    if let Some(index) = ans.iter().position(|x| *x == NonTam2Piece { color, prof })
+                    {
+                        ans.remove(index);
+                    }
+                }
+            }
+        }
+        ans
+    };
+
+    (missing(Side::ASide), missing(Side::IASide))
+}
+
 /// Describes the field, which is defined as a board plus each side's hop1zuo1.
 /// ／フィールドを表す。フィールドとは、盤に両者の手駒を加えたものである。
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+///
+/// `Field::default()` is the empty field, with nothing on the board and in the hop1zuo1s;
+/// it is *not* the initial configuration, which is given by [`yhuap_initial_board`](./fn.yhuap_initial_board.html).
+/// ／`Field::default()` は盤にも手駒にも何もない空のフィールドであり、初期配置ではない。
+/// 初期配置は [`yhuap_initial_board`](./fn.yhuap_initial_board.html) で得られる。
+///
+/// When serialized, the board is written in the row-major order of `Coord` and each hop1zuo1 in the order of [`sort_hop1zuo1`](./fn.sort_hop1zuo1.html),
+/// so that two fields that are equal up to the order of the hop1zuo1s serialize identically. Deserialization accepts any order.
+/// ／シリアライズ時には、盤は `Coord` の行優先の順に、手駒は [`sort_hop1zuo1`](./fn.sort_hop1zuo1.html) の順に書き出す。
+/// したがって、手駒の順番を除いて等しいフィールドは同一の結果になる。デシリアライズはどの順番も受け付ける。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+/// use cetkaik_core::*;
+///
+/// let pawn = NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 };
+/// let king = NonTam2Piece { color: Color::Huok2, prof: Profession::Io };
+/// let a = Field { board: yhuap_initial_board(), a_side_hop1zuo1: vec![pawn, king], ia_side_hop1zuo1: vec![] };
+/// let b = Field { board: yhuap_initial_board(), a_side_hop1zuo1: vec![king, pawn], ia_side_hop1zuo1: vec![] };
+///
+/// let json = serde_json::to_string(&a).unwrap();
+/// assert_eq!(json, serde_json::to_string(&b).unwrap());
+/// assert!(serde_json::from_str::<Field>(&json).unwrap().equals_ignoring_hop1zuo1_order(&b));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Field {
     /// board／盤
+    #[serde(serialize_with = "serialize_board_in_order")]
     pub board: Board,
 
     /// hop1zuo1 for the ASide／A側の手駒
+    #[serde(serialize_with = "serialize_hop1zuo1_sorted")]
     pub a_side_hop1zuo1: Vec<NonTam2Piece>,
 
     /// hop1zuo1 for the IASide／IA側の手駒
+    #[serde(serialize_with = "serialize_hop1zuo1_sorted")]
     pub ia_side_hop1zuo1: Vec<NonTam2Piece>,
 }
 
+fn serialize_board_in_order<S>(board: &Board, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::ser::Serializer,
+{
+    to_ordered_board(board).serialize(serializer)
+}
+
+fn serialize_hop1zuo1_sorted<S>(hop1zuo1: &[NonTam2Piece], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::ser::Serializer,
+{
+    let mut sorted = hop1zuo1.to_vec();
+    sort_hop1zuo1(&mut sorted);
+    sorted.serialize(serializer)
+}
+
 impl Field {
     /// Add a piece to one's hop1zuo1.
     /// ／手駒に駒を追加する。
@@ -281,15 +1020,686 @@ impl Field {
             }
         }
     }
-}
-
-/// Describes which player it is
-/// ／どちら側のプレイヤーであるかを指定する。
-#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash, Deserialize, Serialize)]
-pub enum Side {
-    /// The player whose pieces lie in the A, E and I row when the game starts.
-    /// ／A側プレイヤー。初期状態でA, E, Iの三列に渡って自分の駒が配置されている。
-    ASide,
+
+    /// Computes, in one pass over the board, the squares occupied by each side and the square of Tam2.
+    /// ／盤を一度走査して、各陣営の駒があるマスと、皇のあるマスを求める。
+    ///
+    /// Returns the squares of `ASide`, those of `IASide`, and the square of Tam2, in this order.
+    /// ／`ASide` の駒のマス、`IASide` の駒のマス、皇のマスをこの順に返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let field = Field {
+    ///     board: yhuap_initial_board(),
+    ///     a_side_hop1zuo1: vec![],
+    ///     ia_side_hop1zuo1: vec![],
+    /// };
+    /// let (a_side, ia_side, tam2) = field.occupancy();
+    /// assert_eq!(a_side.len(), 24);
+    /// assert_eq!(ia_side.len(), 24);
+    /// assert!((a_side & ia_side).is_empty());
+    /// assert_eq!(tam2, Some(Coord(Row::O, Column::Z)));
+    /// ```
+    #[must_use]
+    pub fn occupancy(&self) -> (CoordSet, CoordSet, Option<Coord>) {
+        let mut a_side = CoordSet::new();
+        let mut ia_side = CoordSet::new();
+        let mut tam2 = None;
+        for (&coord, piece) in &self.board {
+            match piece {
+                Piece::Tam2 => tam2 = Some(coord),
+                Piece::NonTam2Piece {
+                    side: Side::ASide, ..
+                } => {
+                    a_side.insert(coord);
+                }
+                Piece::NonTam2Piece {
+                    side: Side::IASide, ..
+                } => {
+                    ia_side.insert(coord);
+                }
+            }
+        }
+        (a_side, ia_side, tam2)
+    }
+
+    /// Compares two fields, treating each hop1zuo1 as a multiset so that the order in which pieces were captured does not matter.
+    /// ／二つのフィールドを比較する。手駒は多重集合として扱うので、駒を取った順番は問わない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let io = NonTam2Piece { color: Color::Kok1, prof: Profession::Io };
+    /// let kauk2 = NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 };
+    /// let a = Field { board: yhuap_initial_board(), a_side_hop1zuo1: vec![io, kauk2], ia_side_hop1zuo1: vec![] };
+    /// let b = Field { board: yhuap_initial_board(), a_side_hop1zuo1: vec![kauk2, io], ia_side_hop1zuo1: vec![] };
+    /// assert_ne!(a, b);
+    /// assert!(a.equals_ignoring_hop1zuo1_order(&b));
+    /// ```
+    #[must_use]
+    pub fn equals_ignoring_hop1zuo1_order(&self, other: &Field) -> bool {
+        let sorted = |hop1zuo1: &[NonTam2Piece]| {
+            let mut v = hop1zuo1.to_vec();
+            sort_hop1zuo1(&mut v);
+            v
+        };
+        self.board == other.board
+            && sorted(&self.a_side_hop1zuo1) == sorted(&other.a_side_hop1zuo1)
+            && sorted(&self.ia_side_hop1zuo1) == sorted(&other.ia_side_hop1zuo1)
+    }
+
+    /// Counts the pieces of each side by profession, on the board and in the hop1zuo1s, in one pass.
+    /// ／各陣営の駒を、盤上と手駒のそれぞれについて職種ごとに一度の走査で数える。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let field = Field {
+    ///     board: yhuap_initial_board(),
+    ///     a_side_hop1zuo1: vec![NonTam2Piece { color: Color::Kok1, prof: Profession::Io }],
+    ///     ia_side_hop1zuo1: vec![],
+    /// };
+    /// let summary = field.summary();
+    /// assert_eq!(summary.a_side_board[&Profession::Kauk2], 8);
+    /// assert_eq!(summary.a_side_hop1zuo1[&Profession::Io], 1);
+    /// assert!(summary.ia_side_hop1zuo1.is_empty());
+    /// assert_eq!(summary.a_side_material - summary.ia_side_material, default_piece_value(Profession::Io));
+    ///
+    /// assert!(serde_json::to_string(&summary).is_ok());
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> FieldSummary {
+        let mut ans = FieldSummary::default();
+        for piece in self.board.values() {
+            if let Piece::NonTam2Piece { prof, side, .. } = *piece {
+                let (counts, material) = match side {
+                    Side::ASide => (&mut ans.a_side_board, &mut ans.a_side_material),
+                    Side::IASide => (&mut ans.ia_side_board, &mut ans.ia_side_material),
+                };
+                *counts.entry(prof).or_insert(0) += 1;
+                *material += super::default_piece_value(prof);
+            }
+        }
+        for piece in &self.a_side_hop1zuo1 {
+            *ans.a_side_hop1zuo1.entry(piece.prof).or_insert(0) += 1;
+            ans.a_side_material += super::default_piece_value(piece.prof);
+        }
+        for piece in &self.ia_side_hop1zuo1 {
+            *ans.ia_side_hop1zuo1.entry(piece.prof).or_insert(0) += 1;
+            ans.ia_side_material += super::default_piece_value(piece.prof);
+        }
+        ans
+    }
+
+    /// Returns how many pieces are in the hop1zuo1 of the given side.
+    /// ／指定の陣営の手駒の個数を返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let field = Field {
+    ///     board: yhuap_initial_board(),
+    ///     a_side_hop1zuo1: vec![],
+    ///     ia_side_hop1zuo1: vec![NonTam2Piece { color: Color::Kok1, prof: Profession::Io }],
+    /// };
+    /// assert_eq!(field.hop1zuo1_len(Side::IASide), 1);
+    /// assert_eq!(field.hop1zuo1_len(Side::ASide), 0);
+    /// ```
+    #[must_use]
+    pub const fn hop1zuo1_len(&self, side: Side) -> usize {
+        match side {
+            Side::ASide => self.a_side_hop1zuo1.len(),
+            Side::IASide => self.ia_side_hop1zuo1.len(),
+        }
+    }
+
+    /// Converts into `relative::Field`, assuming that IA is the lowermost row.
+    /// Shorthand for `perspective::to_relative_field(self, Perspective::IaIsDownAndPointsUpward)`.
+    /// ／IAが一番下の行であるとして、`relative::Field` に変換する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let field = Field {
+    ///     board: yhuap_initial_board(),
+    ///     a_side_hop1zuo1: vec![],
+    ///     ia_side_hop1zuo1: vec![NonTam2Piece { color: Color::Kok1, prof: Profession::Io }],
+    /// };
+    /// let relative_field = field.clone().into_relative_ia_down();
+    /// assert_eq!(
+    ///     relative_field.hop1zuo1of_upward,
+    ///     vec![relative::NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Io }]
+    /// );
+    /// assert_eq!(relative_field.into_absolute_ia_down(), field);
+    /// ```
+    #[must_use]
+    pub fn into_relative_ia_down(self) -> super::relative::Field {
+        use super::perspective;
+        perspective::to_relative_field(self, perspective::Perspective::IaIsDownAndPointsUpward)
+    }
+
+    /// Sums `piece_value` over the IA side's pieces and subtracts the sum over the A side's pieces.
+    /// ／IA側の駒について `piece_value` を合計し、A側の駒についての合計を引く。
+    ///
+    /// Both the pieces on the board and those in the hop1zuo1s are counted; Tam2 is ignored.
+    /// ／盤上の駒と手駒の両方を数える。皇は無視する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let mut field = Field {
+    ///     board: yhuap_initial_board(),
+    ///     a_side_hop1zuo1: vec![],
+    ///     ia_side_hop1zuo1: vec![],
+    /// };
+    /// let value = |prof| if prof == Profession::Kauk2 { 1 } else { 5 };
+    /// assert_eq!(field.material_difference(value), 0);
+    ///
+    /// field.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Kauk2, Side::IASide);
+    /// assert_eq!(field.material_difference(value), 1);
+    /// ```
+    #[must_use]
+    pub fn material_difference(&self, piece_value: impl Fn(Profession) -> i32) -> i32 {
+        let on_board: i32 = self
+            .board
+            .values()
+            .map(|piece| match *piece {
+                Piece::Tam2 => 0,
+                Piece::NonTam2Piece {
+                    prof,
+                    side: Side::IASide,
+                    ..
+                } => piece_value(prof),
+                Piece::NonTam2Piece {
+                    prof,
+                    side: Side::ASide,
+                    ..
+                } => -piece_value(prof),
+            })
+            .sum();
+        let ia_side: i32 = self
+            .ia_side_hop1zuo1
+            .iter()
+            .map(|p| piece_value(p.prof))
+            .sum();
+        let a_side: i32 = self
+            .a_side_hop1zuo1
+            .iter()
+            .map(|p| piece_value(p.prof))
+            .sum();
+        on_board + ia_side - a_side
+    }
+
+    /// Iterates over the occupied squares of the board, in the row-major order of `Coord`.
+    /// ／盤上の駒のある升を、`Coord` の行優先の順に列挙する。
+    ///
+    /// The hop1zuo1s are not included. `for (coord, piece) in &field` does the same.
+    /// ／手駒は含まない。`for (coord, piece) in &field` でも同じことができる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    /// use cetkaik_core::*;
+    ///
+    /// let field = Field {
+    ///     board: maplit::hashmap! {
+    ///         Coord(Row::O, Column::Z) => Piece::Tam2,
+    ///         Coord(Row::A, Column::K) => Piece::NonTam2Piece {
+    ///             color: Color::Kok1,
+    ///             prof: Profession::Kua2,
+    ///             side: Side::IASide,
+    ///         },
+    ///     },
+    ///     a_side_hop1zuo1: vec![NonTam2Piece { color: Color::Huok2, prof: Profession::Io }],
+    ///     ia_side_hop1zuo1: vec![],
+    /// };
+    ///
+    /// let coords: Vec<Coord> = field.iter().map(|(coord, _)| coord).collect();
+    /// assert_eq!(coords, vec![Coord(Row::A, Column::K), Coord(Row::O, Column::Z)]);
+    ///
+    /// for (_, piece) in &field {
+    ///     assert!(piece.is_tam2() || piece.has_side(Side::IASide));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> std::vec::IntoIter<(Coord, Piece)> {
+        let mut pieces: Vec<(Coord, Piece)> = self.board.iter().map(|(&c, &p)| (c, p)).collect();
+        pieces.sort_unstable_by_key(|&(coord, _)| coord);
+        pieces.into_iter()
+    }
+
+    /// Moves the piece at `from` to `to`; the piece at `to`, if any, is captured by `capturer`.
+    /// ／`from` にある駒を `to` に動かす。`to` に駒があれば、`capturer` がそれを取る。
+    ///
+    /// The captured piece goes into `capturer`'s hop1zuo1 as described in [`capture_into_hand`](./fn.capture_into_hand.html).
+    /// Only the squares involved are checked; whether the piece can actually move that way is not.
+    /// ／取った駒は [`capture_into_hand`](./fn.capture_into_hand.html) のとおりに `capturer` の手駒に入る。
+    /// 関係するマスの中身のみを確かめ、その駒が実際にそのように動けるかどうかは確かめない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    /// use cetkaik_core::*;
+    ///
+    /// let vessel = Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Nuak1, side: Side::IASide };
+    /// let pawn = Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2, side: Side::ASide };
+    /// let mut field = Field {
+    ///     board: maplit::hashmap! {
+    ///         Coord(Row::AI, Column::Z) => vessel,
+    ///         Coord(Row::Y, Column::Z) => pawn,
+    ///         Coord(Row::O, Column::Z) => Piece::Tam2,
+    ///     },
+    ///     a_side_hop1zuo1: vec![],
+    ///     ia_side_hop1zuo1: vec![],
+    /// };
+    ///
+    /// field.move_piece(Coord(Row::AI, Column::Z), Coord(Row::Y, Column::Z), Side::IASide).unwrap();
+    /// assert_eq!(field.board.get(&Coord(Row::Y, Column::Z)), Some(&vessel));
+    /// assert_eq!(field.board.get(&Coord(Row::AI, Column::Z)), None);
+    /// assert_eq!(field.ia_side_hop1zuo1, vec![NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 }]);
+    ///
+    /// assert_eq!(
+    ///     field.move_piece(Coord(Row::Y, Column::Z), Coord(Row::O, Column::Z), Side::IASide),
+    ///     Err(MoveError::CaptureOfTam2(Coord(Row::O, Column::Z)))
+    /// );
+    /// assert_eq!(
+    ///     field.move_piece(Coord(Row::Y, Column::Z), Coord(Row::Y, Column::Z), Side::ASide),
+    ///     Err(MoveError::SameSquare(Coord(Row::Y, Column::Z)))
+    /// );
+    /// assert_eq!(field.board.len(), 2);
+    /// assert!(field.a_side_hop1zuo1.is_empty());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error, leaving the field untouched, if `from` and `to` are the same square, if `from` is empty,
+    /// or if the piece at `to` cannot be captured by `capturer`.
+    /// ／`from` と `to` が同じマスである場合、`from` が空である場合、`to` にある駒を `capturer` が取れない場合は、
+    /// フィールドには手を付けずにエラーを返す。
+    pub fn move_piece(&mut self, from: Coord, to: Coord, capturer: Side) -> Result<(), MoveError> {
+        let taken = self.validate_move(from, to, capturer)?;
+        if let Some(piece) = self.board.remove(&from) {
+            self.board.insert(to, piece);
+        }
+        if let Some(piece) = taken {
+            capture_into_hand(self, piece, capturer);
+        }
+        Ok(())
+    }
+
+    /// Returns the field after [`move_piece`](#method.move_piece), leaving `self` untouched.
+    /// ／[`move_piece`](#method.move_piece) を適用した後のフィールドを返す。`self` には手を付けない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let field = yhuap_initial_field();
+    /// let next = field.with_move(Coord(Row::AI, Column::Z), Coord(Row::Y, Column::Z), Side::IASide).unwrap();
+    /// assert_eq!(field, yhuap_initial_field());
+    /// assert!(next.board.contains_key(&Coord(Row::Y, Column::Z)));
+    /// assert!(!next.board.contains_key(&Coord(Row::AI, Column::Z)));
+    /// ```
+    ///
+    /// # Errors
+    /// Same as [`move_piece`](#method.move_piece).
+    /// ／[`move_piece`](#method.move_piece) と同じ。
+    pub fn with_move(&self, from: Coord, to: Coord, capturer: Side) -> Result<Self, MoveError> {
+        let mut ans = self.clone();
+        ans.move_piece(from, to, capturer)?;
+        Ok(ans)
+    }
+
+    /// Checks the squares involved in a move, and returns the piece that would be captured.
+    fn validate_move(
+        &self,
+        from: Coord,
+        to: Coord,
+        capturer: Side,
+    ) -> Result<Option<NonTam2Piece>, MoveError> {
+        if from == to {
+            return Err(MoveError::SameSquare(from));
+        }
+        if !self.board.contains_key(&from) {
+            return Err(MoveError::EmptySquare(from));
+        }
+        match self.board.get(&to) {
+            None => Ok(None),
+            Some(Piece::Tam2) => Err(MoveError::CaptureOfTam2(to)),
+            Some(&Piece::NonTam2Piece { side, .. }) if side == capturer => {
+                Err(MoveError::CaptureOfOwnPiece(to))
+            }
+            Some(&Piece::NonTam2Piece { color, prof, .. }) => {
+                Ok(Some(NonTam2Piece { color, prof }))
+            }
+        }
+    }
+}
+
+/// Same as [`Field::iter`](./struct.Field.html#method.iter).
+/// ／[`Field::iter`](./struct.Field.html#method.iter) と同じ。
+impl IntoIterator for &Field {
+    type Item = (Coord, Piece);
+    type IntoIter = std::vec::IntoIter<(Coord, Piece)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Describes why [`Field::move_piece`](./struct.Field.html#method.move_piece) rejected the move.
+/// ／[`Field::move_piece`](./struct.Field.html#method.move_piece) が手を拒否した理由を表す。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MoveError {
+    /// The origin and the destination are the same square.
+    /// ／移動元と移動先が同じマスである。
+    SameSquare(Coord),
+
+    /// There is no piece to move at the square.
+    /// ／そのマスには動かす駒がない。
+    EmptySquare(Coord),
+
+    /// The destination holds a Tam2, which cannot be captured.
+    /// ／移動先に皇があり、取ることはできない。
+    CaptureOfTam2(Coord),
+
+    /// The destination holds a piece of the capturing side itself.
+    /// ／移動先に取る側自身の駒がある。
+    CaptureOfOwnPiece(Coord),
+}
+
+impl std::fmt::Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveError::SameSquare(coord) => {
+                write!(
+                    f,
+                    "cannot move the piece at {} onto the same square",
+                    serialize_coord(*coord)
+                )
+            }
+            MoveError::EmptySquare(coord) => {
+                write!(f, "there is no piece at {}", serialize_coord(*coord))
+            }
+            MoveError::CaptureOfTam2(coord) => {
+                write!(f, "cannot capture the Tam2 at {}", serialize_coord(*coord))
+            }
+            MoveError::CaptureOfOwnPiece(coord) => {
+                write!(
+                    f,
+                    "cannot capture one's own piece at {}",
+                    serialize_coord(*coord)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+/// Summarizes the pieces in a `Field`, as returned by `Field::summary`.
+/// ／`Field::summary` が返す、`Field` 内の駒のまとめ。
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct FieldSummary {
+    /// number of the A side's pieces on the board, by profession／盤上にあるA側の駒の職種ごとの個数
+    pub a_side_board: HashMap<Profession, usize>,
+
+    /// number of the IA side's pieces on the board, by profession／盤上にあるIA側の駒の職種ごとの個数
+    pub ia_side_board: HashMap<Profession, usize>,
+
+    /// number of pieces in the A side's hop1zuo1, by profession／A側の手駒の職種ごとの個数
+    pub a_side_hop1zuo1: HashMap<Profession, usize>,
+
+    /// number of pieces in the IA side's hop1zuo1, by profession／IA側の手駒の職種ごとの個数
+    pub ia_side_hop1zuo1: HashMap<Profession, usize>,
+
+    /// total of [`default_piece_value`](../fn.default_piece_value.html) over the A side's pieces, on the board and in the hop1zuo1
+    /// ／A側の盤上の駒と手駒についての [`default_piece_value`](../fn.default_piece_value.html) の合計
+    pub a_side_material: i32,
+
+    /// total of [`default_piece_value`](../fn.default_piece_value.html) over the IA side's pieces, on the board and in the hop1zuo1
+    /// ／IA側の盤上の駒と手駒についての [`default_piece_value`](../fn.default_piece_value.html) の合計
+    pub ia_side_material: i32,
+}
+
+/// Builds a [`Field`](./struct.Field.html) one piece at a time.
+/// ／[`Field`](./struct.Field.html) を一つずつ駒を置いて組み立てる。
+///
+/// None of the methods panic; the position is validated only when [`build`](#method.build) is called.
+/// ／どのメソッドも panic しない。局面の検証は [`build`](#method.build) を呼んだときにのみ行われる。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let field = FieldBuilder::new()
+///     .place(Coord(Row::O, Column::Z), Piece::Tam2)
+///     .place(
+///         Coord(Row::A, Column::Z),
+///         Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Io, side: Side::ASide },
+///     )
+///     .add_to_hop1zuo1(Side::IASide, NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 })
+///     .build()
+///     .unwrap();
+/// assert_eq!(field.board.len(), 2);
+/// assert_eq!(field.hop1zuo1_len(Side::IASide), 1);
+///
+/// assert_eq!(
+///     FieldBuilder::new().build(),
+///     Err(FieldValidationError::Tam2Count(0))
+/// );
+/// assert_eq!(
+///     FieldBuilder::new()
+///         .place(Coord(Row::O, Column::Z), Piece::Tam2)
+///         .place(Coord(Row::O, Column::Z), Piece::Tam2)
+///         .build(),
+///     Err(FieldValidationError::SquarePlacedTwice(Coord(Row::O, Column::Z)))
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FieldBuilder {
+    field: Field,
+    placed_twice: Option<Coord>,
+}
+
+/// Describes why [`FieldBuilder::build`](./struct.FieldBuilder.html#method.build) rejected the position.
+/// ／[`FieldBuilder::build`](./struct.FieldBuilder.html#method.build) が局面を拒否した理由を表す。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FieldValidationError {
+    /// A piece was placed on a square that already had one. Holds the first such square.
+    /// ／既に駒のあるマスに駒が置かれた。最初のそのようなマスを保持する。
+    SquarePlacedTwice(Coord),
+
+    /// The board does not have exactly one Tam2. Holds the number found.
+    /// ／盤上の皇がちょうど一つではない。見つかった個数を保持する。
+    Tam2Count(usize),
+}
+
+impl std::fmt::Display for FieldValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValidationError::SquarePlacedTwice(coord) => {
+                write!(
+                    f,
+                    "more than one piece was placed at {}",
+                    serialize_coord(*coord)
+                )
+            }
+            FieldValidationError::Tam2Count(n) => {
+                write!(f, "expected exactly one Tam2 on the board, but found {n}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FieldValidationError {}
+
+impl FieldBuilder {
+    /// Starts from the empty field.
+    /// ／空のフィールドから始める。
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places a piece on the square.
+    /// ／マスに駒を置く。
+    #[must_use]
+    pub fn place(mut self, coord: Coord, piece: Piece) -> Self {
+        if self.field.board.insert(coord, piece).is_some() && self.placed_twice.is_none() {
+            self.placed_twice = Some(coord);
+        }
+        self
+    }
+
+    /// Adds a piece to the hop1zuo1 of the side.
+    /// ／指定の陣営の手駒に駒を加える。
+    #[must_use]
+    pub fn add_to_hop1zuo1(mut self, side: Side, piece: NonTam2Piece) -> Self {
+        match side {
+            Side::ASide => self.field.a_side_hop1zuo1.push(piece),
+            Side::IASide => self.field.ia_side_hop1zuo1.push(piece),
+        }
+        self
+    }
+
+    /// Validates and returns the field.
+    /// ／検証した上でフィールドを返す。
+    ///
+    /// # Errors
+    /// Fails if a square was given a piece more than once, or if the board does not have exactly one Tam2.
+    /// ／同じマスに二回以上駒が置かれた場合や、盤上の皇がちょうど一つでない場合は失敗する。
+    pub fn build(self) -> Result<Field, FieldValidationError> {
+        if let Some(coord) = self.placed_twice {
+            return Err(FieldValidationError::SquarePlacedTwice(coord));
+        }
+        let tam2_count = count_tam2(&self.field.board);
+        if tam2_count != 1 {
+            return Err(FieldValidationError::Tam2Count(tam2_count));
+        }
+        Ok(self.field)
+    }
+
+    /// Returns the field without validation. If a square was given a piece more than once, the last one wins.
+    /// ／検証せずにフィールドを返す。同じマスに二回以上駒が置かれた場合は、最後のものが残る。
+    #[must_use]
+    pub fn build_unchecked(self) -> Field {
+        self.field
+    }
+}
+
+/// Describes a hop1zuo1 as a multiset, i.e. how many of each `NonTam2Piece` there are.
+/// ／手駒を、駒ごとの個数を数える多重集合として表す。
+///
+/// Unlike `Vec<NonTam2Piece>`, removing a piece does not require a linear search.
+/// ／`Vec<NonTam2Piece>` とは異なり、駒の削除に線形探索を必要としない。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let mut hop1zuo1 = Hop1Zuo1::new();
+/// hop1zuo1.add(NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 });
+/// hop1zuo1.add(NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 });
+/// assert_eq!(hop1zuo1.len(), 2);
+///
+/// assert!(hop1zuo1.remove(NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 }));
+/// assert!(!hop1zuo1.remove(NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 }));
+///
+/// let v: Vec<NonTam2Piece> = hop1zuo1.into();
+/// assert_eq!(v, vec![NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 }]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Hop1Zuo1 {
+    counts: HashMap<NonTam2Piece, usize>,
+}
+
+impl Hop1Zuo1 {
+    /// Creates an empty hop1zuo1.
+    /// ／空の手駒を作る。
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a piece.
+    /// ／駒を追加する。
+    pub fn add(&mut self, piece: NonTam2Piece) {
+        *self.counts.entry(piece).or_insert(0) += 1;
+    }
+
+    /// Removes a single copy of the piece; returns `false` if none is found.
+    /// ／駒を一つ削除する。見当たらないなら `false` を返す。
+    pub fn remove(&mut self, piece: NonTam2Piece) -> bool {
+        match self.counts.get_mut(&piece) {
+            None => false,
+            Some(1) => {
+                self.counts.remove(&piece);
+                true
+            }
+            Some(count) => {
+                *count -= 1;
+                true
+            }
+        }
+    }
+
+    /// Returns how many copies of the piece there are.
+    /// ／その駒がいくつあるかを返す。
+    #[must_use]
+    pub fn count(&self, piece: NonTam2Piece) -> usize {
+        self.counts.get(&piece).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of pieces.
+    /// ／駒の総数を返す。
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Checks whether there is no piece.
+    /// ／駒が一つもないかどうかを調べる。
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Iterates over the pieces, yielding each piece as many times as it is contained. The order is unspecified.
+    /// ／駒を、含まれている個数の回数だけ列挙する。順番は不定。
+    #[allow(clippy::manual_repeat_n)] // `repeat_n` would require Rust 1.82
+    pub fn iter(&self) -> impl Iterator<Item = NonTam2Piece> + '_ {
+        self.counts
+            .iter()
+            .flat_map(|(&piece, &n)| std::iter::repeat(piece).take(n))
+    }
+}
+
+impl From<Vec<NonTam2Piece>> for Hop1Zuo1 {
+    fn from(v: Vec<NonTam2Piece>) -> Self {
+        let mut ans = Self::new();
+        for piece in v {
+            ans.add(piece);
+        }
+        ans
+    }
+}
+
+impl From<Hop1Zuo1> for Vec<NonTam2Piece> {
+    fn from(h: Hop1Zuo1) -> Self {
+        h.iter().collect()
+    }
+}
+
+/// Describes which player it is
+/// ／どちら側のプレイヤーであるかを指定する。
+#[derive(Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Debug, Hash, Deserialize, Serialize)]
+pub enum Side {
+    /// The player whose pieces lie in the A, E and I row when the game starts.
+    /// ／A側プレイヤー。初期状態でA, E, Iの三列に渡って自分の駒が配置されている。
+    ASide,
 
     /// The player whose pieces lie in the IA, AU and AI row when the game starts.
     /// ／IA側プレイヤー。初期状態でIA, AU, AIの三列に渡って自分の駒が配置されている。
@@ -297,16 +1707,49 @@ pub enum Side {
 }
 
 impl FromStr for Side {
-    type Err = ();
+    type Err = ParseSideError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" => Ok(Side::ASide),
             "IA" => Ok(Side::IASide),
-            _ => Err(()),
+            _ => Err(ParseSideError(s.to_string())),
         }
     }
 }
 
+/// Describes an error that occurred while parsing a [`Side`](./enum.Side.html). Contains the input that failed to parse.
+/// ／[`Side`](./enum.Side.html) のパースに失敗したことを表す。失敗した入力を保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseSideError(pub String);
+
+impl std::fmt::Display for ParseSideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid side: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseSideError {}
+
+impl std::fmt::Display for Side {
+    /// Emits `"A"` or `"IA"`, the same tokens accepted by the [`FromStr`] impl.
+    /// ／`FromStr` が受け付けるのと同じ `"A"` または `"IA"` を出力する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// for side in Side::all() {
+    ///     assert_eq!(side.to_string().parse(), Ok(side));
+    /// }
+    /// assert_eq!(Side::IASide.to_string(), "IA");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Side::ASide => "A",
+            Side::IASide => "IA",
+        })
+    }
+}
+
 use std::ops;
 impl ops::Not for Side {
     type Output = Side;
@@ -319,9 +1762,27 @@ impl ops::Not for Side {
     }
 }
 
-/// Describes the row.
-/// ／盤上の絶対座標のうち行（横列）を表す。
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
+impl Side {
+    /// Returns both sides, `ASide` first.
+    /// ／両陣営を `ASide` から順に返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// let board = yhuap_initial_board();
+    /// for side in Side::all() {
+    ///     assert_eq!(board.values().filter(|p| p.has_side(side)).count(), 24);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn all() -> [Side; 2] {
+        [Side::ASide, Side::IASide]
+    }
+}
+
+/// Describes the row. Rows are ordered from `A` to `IA`.
+/// ／盤上の絶対座標のうち行（横列）を表す。行は `A` から `IA` の順に並ぶ。
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
 #[allow(missing_docs)]
 pub enum Row {
     A,
@@ -335,9 +1796,9 @@ pub enum Row {
     IA,
 }
 
-/// Describes the column.
-/// ／盤上の絶対座標のうち列（縦列）を表す。
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
+/// Describes the column. Columns are ordered from `K` to `P`.
+/// ／盤上の絶対座標のうち列（縦列）を表す。列は `K` から `P` の順に並ぶ。
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
 #[allow(missing_docs)]
 pub enum Column {
     K,
@@ -351,23 +1812,247 @@ pub enum Column {
     P,
 }
 
-/// Describes the absolute coordinate.
-/// ／盤上の絶対座標を表す。
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Copy)]
+impl std::fmt::Display for Row {
+    /// Displays the row as its canonical token, which can be two letters long (`AI`, `AU` and `IA`).
+    /// ／行を正式な表記で表示する。二文字のもの（`AI`, `AU`, `IA`）もある。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// assert_eq!(Row::E.to_string(), "E");
+    /// assert_eq!(Row::AI.to_string(), "AI");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(serialize_row(*self))
+    }
+}
+
+impl std::fmt::Display for Column {
+    /// Displays the column as its canonical single letter.
+    /// ／列を正式な一文字の表記で表示する。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// assert_eq!(Column::Z.to_string(), "Z");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(serialize_column(*self))
+    }
+}
+
+impl Column {
+    /// Returns the single letter denoting the column.
+    /// ／列を表す一文字を返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// assert_eq!(Column::X.as_char(), 'X');
+    /// ```
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        match self {
+            Column::K => 'K',
+            Column::L => 'L',
+            Column::N => 'N',
+            Column::T => 'T',
+            Column::Z => 'Z',
+            Column::X => 'X',
+            Column::C => 'C',
+            Column::M => 'M',
+            Column::P => 'P',
+        }
+    }
+}
+
+/// Describes an error that occurred while converting a `char` into a [`Column`](./enum.Column.html). Contains the character.
+/// ／`char` を [`Column`](./enum.Column.html) に変換するのに失敗したことを表す。その文字を保持する。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ParseColumnError(pub char);
+
+impl std::fmt::Display for ParseColumnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid column: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseColumnError {}
+
+impl TryFrom<char> for Column {
+    type Error = ParseColumnError;
+
+    /// Parses a single uppercase letter into [`Column`](./enum.Column.html).
+    /// ／大文字一文字を [`Column`](./enum.Column.html) にする。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Column::try_from('Z'), Ok(Column::Z));
+    /// assert_eq!(Column::try_from('z'), Err(ParseColumnError('z')));
+    /// assert_eq!(char::from(Column::Z), 'Z');
+    /// ```
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'K' => Ok(Column::K),
+            'L' => Ok(Column::L),
+            'N' => Ok(Column::N),
+            'T' => Ok(Column::T),
+            'Z' => Ok(Column::Z),
+            'X' => Ok(Column::X),
+            'C' => Ok(Column::C),
+            'M' => Ok(Column::M),
+            'P' => Ok(Column::P),
+            _ => Err(ParseColumnError(c)),
+        }
+    }
+}
+
+impl From<Column> for char {
+    fn from(column: Column) -> char {
+        column.as_char()
+    }
+}
+
+/// Describes the absolute coordinate. Coordinates are ordered row-major, from `KA` to `PIA`.
+/// ／盤上の絶対座標を表す。座標は `KA` から `PIA` まで行優先で並ぶ。
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Copy)]
 pub struct Coord(pub Row, pub Column);
 
+impl Coord {
+    /// Checks whether the square is in the given row.
+    /// ／マスが指定の行にあるかを調べる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// assert!(Coord(Row::O, Column::Z).is_in_row(Row::O));
+    /// assert!(!Coord(Row::O, Column::Z).is_in_row(Row::A));
+    /// ```
+    #[must_use]
+    pub const fn is_in_row(self, row: Row) -> bool {
+        row_index(self.0) == row_index(row)
+    }
+
+    /// Checks whether the square is in the given column.
+    /// ／マスが指定の筋にあるかを調べる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// assert!(Coord(Row::O, Column::Z).is_in_column(Column::Z));
+    /// assert!(!Coord(Row::O, Column::Z).is_in_column(Column::K));
+    /// ```
+    #[must_use]
+    pub const fn is_in_column(self, column: Column) -> bool {
+        column_index(self.1) == column_index(column)
+    }
+}
+
+pub(crate) const ROWS: [Row; 9] = [
+    Row::A,
+    Row::E,
+    Row::I,
+    Row::U,
+    Row::O,
+    Row::Y,
+    Row::AI,
+    Row::AU,
+    Row::IA,
+];
+
+pub(crate) const COLUMNS: [Column; 9] = [
+    Column::K,
+    Column::L,
+    Column::N,
+    Column::T,
+    Column::Z,
+    Column::X,
+    Column::C,
+    Column::M,
+    Column::P,
+];
+
+pub(crate) const fn row_index(row: Row) -> usize {
+    match row {
+        Row::A => 0,
+        Row::E => 1,
+        Row::I => 2,
+        Row::U => 3,
+        Row::O => 4,
+        Row::Y => 5,
+        Row::AI => 6,
+        Row::AU => 7,
+        Row::IA => 8,
+    }
+}
+
+pub(crate) const fn column_index(col: Column) -> usize {
+    match col {
+        Column::K => 0,
+        Column::L => 1,
+        Column::N => 2,
+        Column::T => 3,
+        Column::Z => 4,
+        Column::X => 5,
+        Column::C => 6,
+        Column::M => 7,
+        Column::P => 8,
+    }
+}
+
+/// Converts the coordinate into an index in `0..81`, in row-major order from `KA` (0) to `PIA` (80).
+/// ／座標を `0..81` の添字に変換する。`KA`（0）から `PIA`（80）までの行優先の順である。
+///
+/// This is the same order as the one given by `Ord` on [`Coord`](./struct.Coord.html).
+/// ／これは [`Coord`](./struct.Coord.html) の `Ord` による順序と同じである。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(coord_to_index(Coord(Row::A, Column::K)), 0);
+/// assert_eq!(coord_to_index(Coord(Row::E, Column::L)), 10);
+/// assert_eq!(coord_to_index(Coord(Row::IA, Column::P)), 80);
+/// for i in 0..81 {
+///     assert_eq!(coord_to_index(index_to_coord(i).unwrap()), i);
+/// }
+/// ```
+#[must_use]
+pub const fn coord_to_index(Coord(row, col): Coord) -> usize {
+    row_index(row) * 9 + column_index(col)
+}
+
+/// Converts an index given by [`coord_to_index`] back into the coordinate. Returns `None` if the index is not less than 81.
+/// ／[`coord_to_index`] の添字を座標に戻す。添字が81以上なら `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(index_to_coord(40), Some(Coord(Row::O, Column::Z)));
+/// assert_eq!(index_to_coord(81), None);
+/// ```
+#[must_use]
+pub const fn index_to_coord(index: usize) -> Option<Coord> {
+    if index < 81 {
+        Some(Coord(ROWS[index / 9], COLUMNS[index % 9]))
+    } else {
+        None
+    }
+}
+
 impl serde::ser::Serialize for Coord {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(&serialize_coord(*self))
+        serializer.serialize_str(coord_str(*self))
     }
 }
 
 struct CoordVisitor;
 
-impl<'de> serde::de::Visitor<'de> for CoordVisitor {
+impl serde::de::Visitor<'_> for CoordVisitor {
     type Value = Coord;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -378,13 +2063,8 @@ impl<'de> serde::de::Visitor<'de> for CoordVisitor {
     where
         E: serde::de::Error,
     {
-        match Coord::from_str(s) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(serde::de::Error::invalid_value(
-                serde::de::Unexpected::Str(s),
-                &self,
-            )),
-        }
+        Coord::from_str(s)
+            .map_err(|_| serde::de::Error::invalid_value(serde::de::Unexpected::Str(s), &self))
     }
 }
 
@@ -397,15 +2077,81 @@ impl<'de> serde::de::Deserialize<'de> for Coord {
     }
 }
 
+/// `Coord` with named fields.
+/// ／名前付きフィールドを持つ `Coord`。
+///
+/// It serializes as `{ "row": "E", "column": "N" }` rather than as the `"NE"` of [`Coord`](./struct.Coord.html).
+/// ／[`Coord`](./struct.Coord.html) の `"NE"` ではなく、`{ "row": "E", "column": "N" }` としてシリアライズされる。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let coord = CoordStruct::from(Coord(Row::E, Column::N));
+/// let json = serde_json::to_string(&coord).unwrap();
+/// assert_eq!(json, r#"{"row":"E","column":"N"}"#);
+/// assert_eq!(Coord::from(serde_json::from_str::<CoordStruct>(&json).unwrap()), Coord(Row::E, Column::N));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct CoordStruct {
+    /// row／行
+    pub row: Row,
+
+    /// column／列
+    pub column: Column,
+}
+
+impl From<Coord> for CoordStruct {
+    fn from(Coord(row, column): Coord) -> Self {
+        CoordStruct { row, column }
+    }
+}
+
+impl From<CoordStruct> for Coord {
+    fn from(CoordStruct { row, column }: CoordStruct) -> Self {
+        Coord(row, column)
+    }
+}
+
 impl FromStr for Coord {
-    type Err = ();
+    type Err = ParseCoordError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse_coord(s).ok_or(())
+        Self::try_from(s)
     }
 }
 
-/// Parses [`Coord`](type.Coord.html). ／ 文字列を[`Coord`](type.Coord.html)にする。
+/// Describes an error that occurred while parsing a [`Coord`](./struct.Coord.html). Contains the input that failed to parse.
+/// ／[`Coord`](./struct.Coord.html) のパースに失敗したことを表す。失敗した入力を保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseCoordError(pub String);
+
+impl std::fmt::Display for ParseCoordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid coordinate: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseCoordError {}
+
+impl TryFrom<&str> for Coord {
+    type Error = ParseCoordError;
+
+    /// Same as [`parse_coord`], but with an error that tells which input was bad.
+    /// ／[`parse_coord`] と同じだが、どの入力が不正だったかを示すエラーを返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Coord::try_from("LIA"), Ok(Coord(Row::IA, Column::L)));
+    /// assert_eq!(Coord::try_from("LiA"), Err(ParseCoordError("LiA".to_string())));
+    /// ```
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        parse_coord(s).ok_or_else(|| ParseCoordError(s.to_string()))
+    }
+}
+
+/// Parses [`Coord`](type.Coord.html). ／ 文字列を[`Coord`](type.Coord.html)にする。
 /// # Examples
 /// ```
 /// use cetkaik_core::absolute::*;
@@ -426,20 +2172,15 @@ pub fn parse_coord(coord: &str) -> Option<Coord> {
         return None;
     }
 
-    let column = match coord.chars().next() {
-        Some('C') => Some(Column::C),
-        Some('K') => Some(Column::K),
-        Some('L') => Some(Column::L),
-        Some('M') => Some(Column::M),
-        Some('N') => Some(Column::N),
-        Some('P') => Some(Column::P),
-        Some('T') => Some(Column::T),
-        Some('X') => Some(Column::X),
-        Some('Z') => Some(Column::Z),
-        None | Some(_) => None,
-    }?;
-
-    let row = match &coord[1..coord.len()] {
+    let column = Column::try_from(coord.chars().next()?).ok()?;
+
+    let row = parse_row(&coord[1..coord.len()])?;
+
+    Some(Coord(row, column))
+}
+
+fn parse_row(row: &str) -> Option<Row> {
+    match row {
         "A" => Some(Row::A),
         "AI" => Some(Row::AI),
         "AU" => Some(Row::AU),
@@ -450,18 +2191,100 @@ pub fn parse_coord(coord: &str) -> Option<Coord> {
         "Y" => Some(Row::Y),
         "IA" => Some(Row::IA),
         _ => None,
-    }?;
+    }
+}
 
-    Some(Coord(row, column))
+/// Parses [`Coord`](./struct.Coord.html), also accepting the row-then-column order such as `"IAL"`.
+/// ／[`Coord`](./struct.Coord.html) を読む。`"IAL"` のような行→列の順も受け付ける。
+///
+/// The canonical column-then-row order is tried first. Since the letters used for columns and those used for rows do not overlap,
+/// at most one of the two orders can succeed; should both ever succeed, the canonical order wins.
+/// ／まず正式な列→行の順で読む。列の文字と行の文字は重複しないので、高々一方の順しか成功しないが、
+/// 仮に両方成功した場合は正式な順が優先される。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(parse_coord_lenient("LIA"), Some(Coord(Row::IA, Column::L)));
+/// assert_eq!(parse_coord_lenient("IAL"), Some(Coord(Row::IA, Column::L)));
+/// assert_eq!(parse_coord_lenient("IAIA"), None);
+///
+/// // the strict version is unchanged
+/// assert_eq!(parse_coord("IAL"), None);
+/// ```
+#[must_use]
+pub fn parse_coord_lenient(coord: &str) -> Option<Coord> {
+    parse_coord(coord).or_else(|| {
+        let last = coord.chars().last()?;
+        let column = Column::try_from(last).ok()?;
+        let row = parse_row(&coord[..coord.len() - last.len_utf8()])?;
+        Some(Coord(row, column))
+    })
+}
+
+/// Parses [`Coord`](./struct.Coord.html), also accepting full-width and lowercase letters such as `"ｚａｕ"` and `"zau"`.
+/// ／[`Coord`](./struct.Coord.html) を読む。`"ｚａｕ"` や `"zau"` のような全角文字・小文字も受け付ける。
+///
+/// Full-width ASCII characters are first replaced with their ASCII counterparts,
+/// and the result is uppercased before being passed to [`parse_coord`](./fn.parse_coord.html), which stays strict.
+/// Only the full-width ASCII block (U+FF01..=U+FF5E) is folded, rather than performing a full NFKC normalization:
+/// these are the characters that Japanese input methods produce, and folding them needs no extra dependency.
+/// ／まず全角の ASCII 文字を ASCII 文字に置き換え、大文字にしてから
+/// [`parse_coord`](./fn.parse_coord.html) に渡す。`parse_coord` 自体は厳密なままである。
+/// 完全な NFKC 正規化は行わず、全角 ASCII の範囲 (U+FF01..=U+FF5E) のみを変換する。
+/// 日本語の入力方式が生み出すのはこの範囲の文字であり、また追加の依存なしに変換できるためである。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(parse_coord_normalized("ｚａｕ"), Some(Coord(Row::AU, Column::Z)));
+/// assert_eq!(parse_coord_normalized("ＺＡＵ"), Some(Coord(Row::AU, Column::Z)));
+/// assert_eq!(parse_coord_normalized("zau"), Some(Coord(Row::AU, Column::Z)));
+/// assert_eq!(parse_coord_normalized("zq"), None);
+///
+/// assert_eq!(parse_coord("zau"), None);
+/// ```
+#[must_use]
+pub fn parse_coord_normalized(coord: &str) -> Option<Coord> {
+    let normalized: String = coord
+        .chars()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(u32::from(c) - 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        .collect();
+    parse_coord(&normalized.to_uppercase())
+}
+
+/// Returns the initial field, with the board of [`yhuap_initial_board`] and empty hop1zuo1s.
+/// ／[`yhuap_initial_board`] の盤と空の手駒からなる、初期状態のフィールドを返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let field = yhuap_initial_field();
+/// assert_eq!(field.board, yhuap_initial_board());
+/// assert_eq!(field.hop1zuo1_len(Side::ASide), 0);
+/// assert_eq!(field.hop1zuo1_len(Side::IASide), 0);
+/// ```
+#[must_use]
+pub fn yhuap_initial_field() -> Field {
+    Field {
+        board: yhuap_initial_board(),
+        a_side_hop1zuo1: vec![],
+        ia_side_hop1zuo1: vec![],
+    }
 }
 
 /// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
+///
 /// As can be seen in <https://raw.githubusercontent.com/sozysozbot/cerke/master/y1_huap1_summary_en.pdf>,
 /// a black king is in ZIA while a red king is in ZA.
+///
 /// ／官定で定められた初期配置を与える。
 /// <https://raw.githubusercontent.com/sozysozbot/cerke/master/y1_huap1_summary.pdf> にあるように、
 /// ZIAには黒王、ZAには赤王がある。
-/// 
+///
 /// # Examples
 /// ```
 /// use cetkaik_core::absolute::{yhuap_initial_board, Row, Column, Coord, Piece, Side};
@@ -472,9 +2295,9 @@ pub fn parse_coord(coord: &str) -> Option<Coord> {
 ///     yhuap_initial_board().get(&Coord(Row::IA, Column::Z)).unwrap()
 /// )
 /// ```
-/// 
+///
 /// This function is consistent with `relative::yhuap_initial_board_where_black_king_points_upward`:
-/// 
+///
 /// ```
 /// use cetkaik_core::{absolute, relative, perspective};
 /// assert_eq!(perspective::to_absolute_board(
@@ -548,30 +2371,1590 @@ pub fn yhuap_initial_board() -> Board {
 ///
 #[must_use]
 pub fn serialize_coord(coord: Coord) -> String {
-    let Coord(row, column) = coord;
+    coord_str(coord).to_string()
+}
+
+const COORD_STRS: [&str; 81] = [
+    "KA", "LA", "NA", "TA", "ZA", "XA", "CA", "MA", "PA", "KE", "LE", "NE", "TE", "ZE", "XE", "CE",
+    "ME", "PE", "KI", "LI", "NI", "TI", "ZI", "XI", "CI", "MI", "PI", "KU", "LU", "NU", "TU", "ZU",
+    "XU", "CU", "MU", "PU", "KO", "LO", "NO", "TO", "ZO", "XO", "CO", "MO", "PO", "KY", "LY", "NY",
+    "TY", "ZY", "XY", "CY", "MY", "PY", "KAI", "LAI", "NAI", "TAI", "ZAI", "XAI", "CAI", "MAI",
+    "PAI", "KAU", "LAU", "NAU", "TAU", "ZAU", "XAU", "CAU", "MAU", "PAU", "KIA", "LIA", "NIA",
+    "TIA", "ZIA", "XIA", "CIA", "MIA", "PIA",
+];
+
+/// Returns the string of [`serialize_coord`](./fn.serialize_coord.html) from a precomputed table, without allocating.
+/// ／[`serialize_coord`](./fn.serialize_coord.html) と同じ文字列を、メモリ確保をせずに事前計算された表から返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(coord_str(Coord(Row::AU, Column::Z)), "ZAU");
+/// for i in 0..81 {
+///     let coord = index_to_coord(i).unwrap();
+///     assert_eq!(coord_str(coord), serialize_coord(coord));
+///     assert_eq!(parse_coord(coord_str(coord)), Some(coord));
+/// }
+/// ```
+#[must_use]
+pub const fn coord_str(coord: Coord) -> &'static str {
+    COORD_STRS[coord_to_index(coord)]
+}
+
+/// Writes [`Coord`](./struct.Coord.html) into a formatter or a `String` without allocating.
+/// ／[`Coord`](./struct.Coord.html) を、メモリ確保をせずにフォーマッタや `String` に書き込む。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut s = String::new();
+/// write_coord(Coord(Row::AU, Column::Z), &mut s).unwrap();
+/// assert_eq!(s, "ZAU");
+/// ```
+///
+/// # Errors
+/// Returns an error if the writer does.
+/// ／書き込み先がエラーを返したときはエラーを返す。
+pub fn write_coord(coord: Coord, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+    w.write_str(coord_str(coord))
+}
+
+const fn serialize_column(column: Column) -> &'static str {
+    match column {
+        Column::K => "K",
+        Column::L => "L",
+        Column::M => "M",
+        Column::N => "N",
+        Column::P => "P",
+        Column::Z => "Z",
+        Column::X => "X",
+        Column::C => "C",
+        Column::T => "T",
+    }
+}
+
+const fn serialize_row(row: Row) -> &'static str {
+    match row {
+        Row::A => "A",
+        Row::E => "E",
+        Row::I => "I",
+        Row::O => "O",
+        Row::U => "U",
+        Row::Y => "Y",
+        Row::IA => "IA",
+        Row::AI => "AI",
+        Row::AU => "AU",
+    }
+}
+
+/// Describes a move: a piece goes to a square, either from another square or from a hop1zuo1.
+/// ／一手を表す。駒が、盤上の別のマスまたは手駒から、あるマスへと移る。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Move {
+    /// the square the piece leaves; `None` means that the piece is dropped from a hop1zuo1
+    /// ／駒が元々いたマス。`None` は手駒から打つことを表す。
+    pub from: Option<Coord>,
+
+    /// the square the piece arrives at／駒の移動先のマス
+    pub to: Coord,
+
+    /// the piece that moves／動く駒
+    pub piece: Piece,
+}
+
+/// Describes an error that occurred while parsing a [`Move`](./struct.Move.html). Contains the input that failed to parse.
+/// ／[`Move`](./struct.Move.html) のパースに失敗したことを表す。失敗した入力を保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseMoveError(pub String);
+
+impl std::fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid move: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseMoveError {}
+
+const fn serialize_color_letter(color: Color) -> char {
+    match color {
+        Color::Kok1 => 'R',
+        Color::Huok2 => 'B',
+    }
+}
+
+const fn parse_color_letter(c: char) -> Option<Color> {
+    match c {
+        'R' => Some(Color::Kok1),
+        'B' => Some(Color::Huok2),
+        _ => None,
+    }
+}
+
+fn serialize_piece_notation(piece: Piece) -> String {
+    match piece {
+        Piece::Tam2 => "tam2".to_string(),
+        Piece::NonTam2Piece { color, prof, side } => format!(
+            "{}{}{}",
+            serialize_color_letter(color),
+            prof.notation_letter(),
+            side
+        ),
+    }
+}
+
+fn parse_piece_notation(s: &str) -> Option<Piece> {
+    if s == "tam2" {
+        return Some(Piece::Tam2);
+    }
+    let mut chars = s.chars();
+    let color = parse_color_letter(chars.next()?)?;
+    let prof = Profession::from_notation_letter(chars.next()?)?;
+    let side = Side::from_str(chars.as_str()).ok()?;
+    Some(Piece::NonTam2Piece { color, prof, side })
+}
+
+/// Serializes [`Move`](./struct.Move.html) as three space-separated tokens: the origin, the piece and the destination.
+/// ／[`Move`](./struct.Move.html) を、移動元・駒・移動先の三つの空白区切りの文字列にする。
+///
+/// The origin is `*` when the piece is dropped from a hop1zuo1. The piece is `tam2` for Tam2;
+/// otherwise, it is the color (`R` for red, `B` for black), followed by
+/// [`Profession::notation_letter`](../enum.Profession.html#method.notation_letter) and then the side (`A` or `IA`).
+/// ／手駒から打つ場合、移動元は `*` である。駒は、皇なら `tam2`、そうでなければ色（赤は `R`、黒は `B`）、
+/// [`Profession::notation_letter`](../enum.Profession.html#method.notation_letter)、所属側（`A` または `IA`）をこの順に並べたものである。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let mv = Move {
+///     from: Some(Coord(Row::AI, Column::Z)),
+///     to: Coord(Row::Y, Column::Z),
+///     piece: Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Nuak1, side: Side::IASide },
+/// };
+/// assert_eq!(serialize_move(&mv), "ZAI BvIA ZY");
+///
+/// let drop = Move {
+///     from: None,
+///     to: Coord(Row::I, Column::K),
+///     piece: Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2, side: Side::ASide },
+/// };
+/// assert_eq!(serialize_move(&drop), "* RpA KI");
+/// ```
+#[must_use]
+pub fn serialize_move(mv: &Move) -> String {
     format!(
-        "{}{}",
-        match column {
-            Column::K => "K",
-            Column::L => "L",
-            Column::M => "M",
-            Column::N => "N",
-            Column::P => "P",
-            Column::Z => "Z",
-            Column::X => "X",
-            Column::C => "C",
-            Column::T => "T",
-        },
-        match row {
-            Row::A => "A",
-            Row::E => "E",
-            Row::I => "I",
-            Row::O => "O",
-            Row::U => "U",
-            Row::Y => "Y",
-            Row::IA => "IA",
-            Row::AI => "AI",
-            Row::AU => "AU",
-        }
+        "{} {} {}",
+        mv.from.map_or_else(|| "*".to_string(), serialize_coord),
+        serialize_piece_notation(mv.piece),
+        serialize_coord(mv.to)
     )
 }
+
+/// Parses the notation produced by [`serialize_move`](./fn.serialize_move.html).
+/// ／[`serialize_move`](./fn.serialize_move.html) の出力を [`Move`](./struct.Move.html) に戻す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mv = parse_move("* tam2 ZO").unwrap();
+/// assert_eq!(mv, Move { from: None, to: Coord(Row::O, Column::Z), piece: Piece::Tam2 });
+/// assert_eq!(serialize_move(&mv), "* tam2 ZO");
+///
+/// assert_eq!(parse_move("ZO tam2"), Err(ParseMoveError("ZO tam2".to_string())));
+/// ```
+///
+/// # Errors
+/// Returns an error containing the input if it is not a valid notation.
+/// ／入力が正しい表記でなければ、その入力を含むエラーを返す。
+pub fn parse_move(s: &str) -> Result<Move, ParseMoveError> {
+    let parse = || {
+        let mut tokens = s.split(' ');
+        let from = match tokens.next()? {
+            "*" => None,
+            coord => Some(parse_coord(coord)?),
+        };
+        let piece = parse_piece_notation(tokens.next()?)?;
+        let to = parse_coord(tokens.next()?)?;
+        if tokens.next().is_some() {
+            return None;
+        }
+        Some(Move { from, to, piece })
+    };
+    parse().ok_or_else(|| ParseMoveError(s.to_string()))
+}
+
+/// Describes an error that occurred while parsing a position string. Contains the input that failed to parse.
+/// ／局面文字列のパースに失敗したことを表す。失敗した入力を保持する。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParsePositionError(pub String);
+
+impl std::fmt::Display for ParsePositionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid position: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParsePositionError {}
+
+/// Serializes [`Field`](./struct.Field.html) as a single line, in the manner of FEN in chess.
+/// ／[`Field`](./struct.Field.html) を、チェスの FEN のように一行の文字列にする。
+///
+/// The string consists of three space-separated parts: the board, the hop1zuo1 of `ASide`, and the hop1zuo1 of `IASide`.
+/// The board lists the rows from A to IA separated by `/`, and each row lists the squares from column K to column P,
+/// writing Tam2 as `T`, any other piece in the notation used by [`serialize_move`](./fn.serialize_move.html), and a run of empty squares as its length.
+/// Tam2 is not written as `tam2`, so that no digit other than a run length appears in a row.
+/// Each hop1zuo1 is the concatenation of the color (`R` or `B`) and the profession letter of each piece, or `-` if empty.
+/// ／文字列は空白で区切られた三つの部分、すなわち盤、`ASide` の手駒、`IASide` の手駒からなる。
+/// 盤は A 行から IA 行までを `/` で区切って並べ、各行は K 筋から P 筋までのマスを、皇なら `T`、その他の駒なら [`serialize_move`](./fn.serialize_move.html) と同じ表記で、
+/// 連続する空きマスならその個数で書く。行の中に空きマスの個数以外の数字が現れないよう、皇は `tam2` ではなく `T` と書く。
+/// 手駒は各駒の色（`R` または `B`）と職種の文字を連結したもので、空なら `-` である。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(to_position_string(&Field::default()), "9/9/9/9/9/9/9/9/9 - -");
+///
+/// let field = FieldBuilder::new()
+///     .place(Coord(Row::O, Column::Z), Piece::Tam2)
+///     .place(
+///         Coord(Row::A, Column::Z),
+///         Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Io, side: Side::ASide },
+///     )
+///     .add_to_hop1zuo1(Side::IASide, NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 })
+///     .build()
+///     .unwrap();
+/// assert_eq!(to_position_string(&field), "4RkA4/9/9/9/4T4/9/9/9/9 - Bp");
+/// ```
+#[must_use]
+pub fn to_position_string(field: &Field) -> String {
+    let mut ans = String::new();
+    for (i, &row) in ROWS.iter().enumerate() {
+        if i > 0 {
+            ans.push('/');
+        }
+        let mut empties = 0;
+        for &col in &COLUMNS {
+            match field.board.get(&Coord(row, col)) {
+                None => empties += 1,
+                Some(&piece) => {
+                    if empties > 0 {
+                        ans.push_str(&empties.to_string());
+                        empties = 0;
+                    }
+                    match piece {
+                        Piece::Tam2 => ans.push('T'),
+                        Piece::NonTam2Piece { .. } => {
+                            ans.push_str(&serialize_piece_notation(piece));
+                        }
+                    }
+                }
+            }
+        }
+        if empties > 0 {
+            ans.push_str(&empties.to_string());
+        }
+    }
+    for hop1zuo1 in [&field.a_side_hop1zuo1, &field.ia_side_hop1zuo1] {
+        ans.push(' ');
+        if hop1zuo1.is_empty() {
+            ans.push('-');
+        }
+        for piece in hop1zuo1 {
+            ans.push(serialize_color_letter(piece.color));
+            ans.push(piece.prof.notation_letter());
+        }
+    }
+    ans
+}
+
+/// Parses the string produced by [`to_position_string`](./fn.to_position_string.html).
+/// ／[`to_position_string`](./fn.to_position_string.html) の出力を [`Field`](./struct.Field.html) に戻す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let field = Field {
+///     board: yhuap_initial_board(),
+///     a_side_hop1zuo1: vec![],
+///     ia_side_hop1zuo1: vec![],
+/// };
+/// assert_eq!(from_position_string(&to_position_string(&field)), Ok(field));
+///
+/// assert!(from_position_string("9/9/9/9/9/9/9/9 - -").is_err());
+/// assert!(from_position_string("8/9/9/9/9/9/9/9/9 - -").is_err());
+///
+/// // a run of empty squares is written as a single digit
+/// assert!(from_position_string("45/9/9/9/9/9/9/9/9 - -").is_err());
+/// assert!(from_position_string("4T13/9/9/9/9/9/9/9/9 - -").is_err());
+/// ```
+///
+/// # Errors
+/// Returns an error containing the input if it is not a valid position string.
+/// ／入力が正しい局面文字列でなければ、その入力を含むエラーを返す。
+pub fn from_position_string(s: &str) -> Result<Field, ParsePositionError> {
+    let parse = || {
+        let mut parts = s.split(' ');
+        let mut ranks = parts.next()?.split('/');
+        let mut board = Board::new();
+        for &row in &ROWS {
+            parse_rank(ranks.next()?, row, &mut board)?;
+        }
+        if ranks.next().is_some() {
+            return None;
+        }
+        let a_side_hop1zuo1 = parse_hop1zuo1_notation(parts.next()?)?;
+        let ia_side_hop1zuo1 = parse_hop1zuo1_notation(parts.next()?)?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Field {
+            board,
+            a_side_hop1zuo1,
+            ia_side_hop1zuo1,
+        })
+    };
+    parse().ok_or_else(|| ParsePositionError(s.to_string()))
+}
+
+fn parse_rank(rank: &str, row: Row, board: &mut Board) -> Option<()> {
+    let mut col_index = 0;
+    let mut rest = rank;
+    let mut after_digit = false;
+    while let Some(c) = rest.chars().next() {
+        if let Some(n) = c.to_digit(10) {
+            if n == 0 || after_digit {
+                return None;
+            }
+            col_index += usize::try_from(n).ok()?;
+            rest = &rest[1..];
+            after_digit = true;
+            continue;
+        }
+        after_digit = false;
+        if c == 'T' {
+            board.insert(Coord(row, *COLUMNS.get(col_index)?), Piece::Tam2);
+            col_index += 1;
+            rest = &rest[1..];
+            continue;
+        }
+        let len = if rest.get(2..)?.starts_with("IA") {
+            4
+        } else {
+            3
+        };
+        let piece = parse_piece_notation(rest.get(..len)?)?;
+        board.insert(Coord(row, *COLUMNS.get(col_index)?), piece);
+        col_index += 1;
+        rest = &rest[len..];
+    }
+    if col_index == 9 {
+        Some(())
+    } else {
+        None
+    }
+}
+
+#[allow(clippy::manual_is_multiple_of)] // `is_multiple_of` would require Rust 1.87
+fn parse_hop1zuo1_notation(s: &str) -> Option<Vec<NonTam2Piece>> {
+    if s == "-" {
+        return Some(vec![]);
+    }
+    let chars: Vec<char> = s.chars().collect();
+    if chars.is_empty() || chars.len() % 2 != 0 {
+        return None;
+    }
+    chars
+        .chunks(2)
+        .map(|pair| {
+            Some(NonTam2Piece {
+                color: parse_color_letter(pair[0])?,
+                prof: Profession::from_notation_letter(pair[1])?,
+            })
+        })
+        .collect()
+}
+
+/// Describes a set of squares as a bitboard; each of the 81 squares corresponds to a bit of a `u128`.
+/// ／マスの集合をビットボードとして表す。81マスのそれぞれが `u128` の各ビットに対応する。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut a = CoordSet::new();
+/// a.insert(Coord(Row::A, Column::K));
+/// a.insert(Coord(Row::O, Column::Z));
+/// let mut b = CoordSet::new();
+/// b.insert(Coord(Row::O, Column::Z));
+///
+/// assert_eq!((a & b).iter().collect::<Vec<_>>(), vec![Coord(Row::O, Column::Z)]);
+/// assert_eq!((a | b).len(), 2);
+/// assert_eq!((!a).len(), 79);
+/// assert!(!(!a).contains(Coord(Row::A, Column::K)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct CoordSet(u128);
+
+const COORD_SET_MASK: u128 = (1 << 81) - 1;
+
+const fn coord_bit(coord: Coord) -> u128 {
+    1 << coord_to_index(coord)
+}
+
+impl CoordSet {
+    /// Creates an empty set.
+    /// ／空集合を作る。
+    #[must_use]
+    pub const fn new() -> Self {
+        CoordSet(0)
+    }
+
+    /// Adds a square to the set. Returns whether the square was newly inserted.
+    /// ／マスを集合に追加する。新たに追加されたかどうかを返す。
+    pub const fn insert(&mut self, coord: Coord) -> bool {
+        let bit = coord_bit(coord);
+        let is_new = self.0 & bit == 0;
+        self.0 |= bit;
+        is_new
+    }
+
+    /// Removes a square from the set. Returns whether the square was present.
+    /// ／マスを集合から削除する。そのマスが含まれていたかどうかを返す。
+    pub const fn remove(&mut self, coord: Coord) -> bool {
+        let bit = coord_bit(coord);
+        let was_present = self.0 & bit != 0;
+        self.0 &= !bit;
+        was_present
+    }
+
+    /// Checks whether the set contains the square.
+    /// ／集合がそのマスを含むかどうかを調べる。
+    #[must_use]
+    pub const fn contains(self, coord: Coord) -> bool {
+        self.0 & coord_bit(coord) != 0
+    }
+
+    /// Returns the number of squares in the set.
+    /// ／集合に含まれるマスの数を返す。
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Checks whether the set is empty.
+    /// ／空集合であるかどうかを調べる。
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterates over the squares in the set, from `KA` to `PIA` in row-major order.
+    /// ／集合に含まれるマスを、`KA` から `PIA` まで行優先で列挙する。
+    pub fn iter(self) -> impl Iterator<Item = Coord> {
+        (0..81)
+            .filter(move |i| self.0 & (1 << i) != 0)
+            .filter_map(index_to_coord)
+    }
+}
+
+impl std::iter::FromIterator<Coord> for CoordSet {
+    fn from_iter<I: IntoIterator<Item = Coord>>(iter: I) -> Self {
+        let mut ans = CoordSet::new();
+        for coord in iter {
+            ans.insert(coord);
+        }
+        ans
+    }
+}
+
+impl ops::BitOr for CoordSet {
+    type Output = CoordSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        CoordSet(self.0 | rhs.0)
+    }
+}
+
+impl ops::BitAnd for CoordSet {
+    type Output = CoordSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        CoordSet(self.0 & rhs.0)
+    }
+}
+
+impl ops::Not for CoordSet {
+    type Output = CoordSet;
+
+    fn not(self) -> Self::Output {
+        CoordSet(!self.0 & COORD_SET_MASK)
+    }
+}
+
+/// Describes one of the eight directions on the board, in terms of absolute coordinates.
+/// ／盤上の八方向のうちの一つを、絶対座標の観点から表す。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    /// towards the IA row／IA行の方向
+    TowardIA,
+    /// towards the A row／A行の方向
+    TowardA,
+    /// towards the P column／P列の方向
+    TowardP,
+    /// towards the K column／K列の方向
+    TowardK,
+    /// towards the IA row and the P column／IA行かつP列の方向
+    TowardIAP,
+    /// towards the IA row and the K column／IA行かつK列の方向
+    TowardIAK,
+    /// towards the A row and the P column／A行かつP列の方向
+    TowardAP,
+    /// towards the A row and the K column／A行かつK列の方向
+    TowardAK,
+}
+
+impl Direction {
+    /// Returns all the eight directions.
+    /// ／八方向全てを返す。
+    #[must_use]
+    pub const fn all() -> [Direction; 8] {
+        [
+            Direction::TowardIA,
+            Direction::TowardA,
+            Direction::TowardP,
+            Direction::TowardK,
+            Direction::TowardIAP,
+            Direction::TowardIAK,
+            Direction::TowardAP,
+            Direction::TowardAK,
+        ]
+    }
+
+    /// Returns the change in the row index and the column index, where the row index increases towards IA and the column index increases towards P.
+    /// ／行番号と列番号の変化量を返す。行番号はIAに向かって、列番号はPに向かって増える。
+    #[must_use]
+    pub const fn delta(self) -> (i32, i32) {
+        match self {
+            Direction::TowardIA => (1, 0),
+            Direction::TowardA => (-1, 0),
+            Direction::TowardP => (0, 1),
+            Direction::TowardK => (0, -1),
+            Direction::TowardIAP => (1, 1),
+            Direction::TowardIAK => (1, -1),
+            Direction::TowardAP => (-1, 1),
+            Direction::TowardAK => (-1, -1),
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn indices(Coord(row, col): Coord) -> (i32, i32) {
+    // both indices are less than 9, so the casts are lossless
+    (row_index(row) as i32, column_index(col) as i32)
+}
+
+fn from_indices(row: i32, col: i32) -> Option<Coord> {
+    let row = *ROWS.get(usize::try_from(row).ok()?)?;
+    let col = *COLUMNS.get(usize::try_from(col).ok()?)?;
+    Some(Coord(row, col))
+}
+
+/// Moves the coordinate by one square in the given direction. Returns `None` if the result falls outside the board.
+/// ／座標を指定の方向に1マス動かす。盤外に出るなら `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(step(Coord(Row::O, Column::Z), Direction::TowardIAK), Some(Coord(Row::Y, Column::T)));
+/// assert_eq!(step(Coord(Row::A, Column::Z), Direction::TowardA), None);
+/// ```
+#[must_use]
+pub fn step(coord: Coord, dir: Direction) -> Option<Coord> {
+    let (row, col) = indices(coord);
+    let (dr, dc) = dir.delta();
+    from_indices(row + dr, col + dc)
+}
+
+/// Returns the direction in which `to` lies as seen from `from`, if `to` lies exactly along one of the eight rays from `from`.
+/// ／`from` から見て `to` が八方向のいずれかの直線上にあるなら、その方向を返す。
+///
+/// Returns `None` if `to` is not on any of the rays, or if `from` and `to` are the same square.
+/// ／いずれの直線上にもない場合や、`from` と `to` が同じマスである場合は `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(
+///     direction_between(Coord(Row::A, Column::K), Coord(Row::IA, Column::P)),
+///     Some(Direction::TowardIAP)
+/// );
+/// assert_eq!(
+///     direction_between(Coord(Row::O, Column::Z), Coord(Row::O, Column::K)),
+///     Some(Direction::TowardK)
+/// );
+/// assert_eq!(direction_between(Coord(Row::A, Column::K), Coord(Row::E, Column::N)), None);
+/// assert_eq!(direction_between(Coord(Row::A, Column::K), Coord(Row::A, Column::K)), None);
+/// ```
+#[must_use]
+pub fn direction_between(from: Coord, to: Coord) -> Option<Direction> {
+    let (dr, dc) = delta(from, to);
+    if (dr, dc) == (0, 0) || (dr != 0 && dc != 0 && dr.abs() != dc.abs()) {
+        return None;
+    }
+    Direction::all()
+        .iter()
+        .copied()
+        .find(|dir| dir.delta() == (dr.signum(), dc.signum()))
+}
+
+/// Returns the signed offset `(row_delta, col_delta)` from `from` to `to`, positive toward the IA row and toward the P column.
+/// ／`from` から `to` への符号付きのずれ `(row_delta, col_delta)` を返す。IA行の方向およびP列の方向が正。
+///
+/// [`distance`](./fn.distance.html) is the larger of the absolute values of the two.
+/// ／[`distance`](./fn.distance.html) は両者の絶対値のうち大きい方である。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(delta(Coord(Row::A, Column::K), Coord(Row::E, Column::N)), (1, 2));
+/// assert_eq!(delta(Coord(Row::IA, Column::Z), Coord(Row::O, Column::L)), (-4, -3));
+/// ```
+#[must_use]
+pub const fn delta(from: Coord, to: Coord) -> (i32, i32) {
+    let (from_row, from_col) = indices(from);
+    let (to_row, to_col) = indices(to);
+    (to_row - from_row, to_col - from_col)
+}
+
+/// Returns the nine squares in the given row, ordered from column K to column P.
+/// ／指定の行に属する9マスを、K筋からP筋の順に返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let coords = row_coords(Row::AU);
+/// assert_eq!(coords[0], Coord(Row::AU, Column::K));
+/// assert_eq!(coords[8], Coord(Row::AU, Column::P));
+/// ```
+#[must_use]
+pub fn row_coords(row: Row) -> [Coord; 9] {
+    COLUMNS.map(|col| Coord(row, col))
+}
+
+/// Returns the nine squares in the given column, ordered from row A to row IA.
+/// ／指定の筋に属する9マスを、A行からIA行の順に返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let coords = column_coords(Column::Z);
+/// assert_eq!(coords[0], Coord(Row::A, Column::Z));
+/// assert_eq!(coords[8], Coord(Row::IA, Column::Z));
+///
+/// // checking whether an entire file is empty
+/// let board = yhuap_initial_board();
+/// assert!(!column_coords(Column::Z).iter().all(|c| !board.contains_key(c)));
+/// ```
+#[must_use]
+pub fn column_coords(col: Column) -> [Coord; 9] {
+    ROWS.map(|row| Coord(row, col))
+}
+
+/// Checks whether every square strictly between `from` and `to` is empty.
+/// ／`from` と `to` の間（両端を除く）のマスがすべて空いているかを調べる。
+///
+/// Returns `None` if `from` and `to` do not lie on a common ray, i.e. when [`direction_between`] returns `None`.
+/// ／`from` と `to` が同一直線上にない場合（[`direction_between`] が `None` を返す場合）は `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let board = yhuap_initial_board();
+///
+/// // adjacent squares have nothing in between
+/// assert_eq!(is_path_clear(&board, Coord(Row::AI, Column::K), Coord(Row::AU, Column::K)), Some(true));
+///
+/// // the middle rows are empty at the start
+/// assert_eq!(is_path_clear(&board, Coord(Row::I, Column::K), Coord(Row::AI, Column::K)), Some(true));
+///
+/// // but the row I pieces block the way from row E to row AI
+/// assert_eq!(is_path_clear(&board, Coord(Row::E, Column::K), Coord(Row::AI, Column::K)), Some(false));
+///
+/// // not collinear
+/// assert_eq!(is_path_clear(&board, Coord(Row::A, Column::K), Coord(Row::E, Column::N)), None);
+/// ```
+#[must_use]
+pub fn is_path_clear(board: &Board, from: Coord, to: Coord) -> Option<bool> {
+    let dir = direction_between(from, to)?;
+    let mut coord = step(from, dir)?;
+    while coord != to {
+        if board.contains_key(&coord) {
+            return Some(false);
+        }
+        coord = step(coord, dir)?;
+    }
+    Some(true)
+}
+
+/// Returns the canonical representative of the position among its symmetric images.
+/// ／局面と、それと対称な局面のうちから、代表となるものを返す。
+///
+/// The images considered are the position itself, its 180° rotation (which also swaps the sides and the two hop1zuo1s),
+/// its color swap (`Kok1` ⇔ `Huok2`), and the rotation combined with the color swap.
+/// Among those, the one that is smallest when compared square by square in row-major order, and then by the hop1zuo1s, is returned,
+/// with both hop1zuo1s sorted. Two positions that are symmetric to each other therefore canonicalize to the identical `Field`.
+/// ／考慮する像は、局面そのもの、180°回転（陣営と両者の手駒も入れ替わる）、色の入れ替え（赤⇔黒）、回転と色の入れ替えの組み合わせである。
+/// その中から、マスごとに行優先の順で比較し、続いて手駒で比較して最小のものを、両者の手駒を整列した上で返す。
+/// したがって、互いに対称な二つの局面は同一の `Field` になる。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let field = Field {
+///     board: board_from_iter(vec![(
+///         Coord(Row::A, Column::K),
+///         Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Io, side: Side::ASide },
+///     )]),
+///     a_side_hop1zuo1: vec![NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 }],
+///     ia_side_hop1zuo1: vec![],
+/// };
+/// let rotated = Field {
+///     board: board_from_iter(vec![(
+///         Coord(Row::IA, Column::P),
+///         Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Io, side: Side::IASide },
+///     )]),
+///     a_side_hop1zuo1: vec![],
+///     ia_side_hop1zuo1: vec![NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 }],
+/// };
+/// assert_eq!(canonicalize(&field), canonicalize(&rotated));
+/// assert_eq!(canonicalize(&canonicalize(&field)), canonicalize(&field));
+/// ```
+#[must_use]
+pub fn canonicalize(field: &Field) -> Field {
+    let rotated = rotate_field(field);
+    let mut canonical = field.clone();
+    for candidate in [
+        swap_field_colors(field),
+        swap_field_colors(&rotated),
+        rotated,
+    ] {
+        if field_key(&candidate) < field_key(&canonical) {
+            canonical = candidate;
+        }
+    }
+    sort_hop1zuo1(&mut canonical.a_side_hop1zuo1);
+    sort_hop1zuo1(&mut canonical.ia_side_hop1zuo1);
+    canonical
+}
+
+fn rotate_field(field: &Field) -> Field {
+    Field {
+        board: field
+            .board
+            .iter()
+            .map(|(&coord, &piece)| {
+                let (row, col) = indices(coord);
+                let coord =
+                    from_indices(8 - row, 8 - col).expect("rotation stays within the board");
+                let piece = match piece {
+                    Piece::Tam2 => Piece::Tam2,
+                    Piece::NonTam2Piece { color, prof, side } => Piece::NonTam2Piece {
+                        color,
+                        prof,
+                        side: !side,
+                    },
+                };
+                (coord, piece)
+            })
+            .collect(),
+        a_side_hop1zuo1: field.ia_side_hop1zuo1.clone(),
+        ia_side_hop1zuo1: field.a_side_hop1zuo1.clone(),
+    }
+}
+
+fn swap_field_colors(field: &Field) -> Field {
+    let swap = |p: NonTam2Piece| NonTam2Piece {
+        color: !p.color,
+        prof: p.prof,
+    };
+    Field {
+        board: field
+            .board
+            .iter()
+            .map(|(&coord, &piece)| {
+                let piece = match piece {
+                    Piece::Tam2 => Piece::Tam2,
+                    Piece::NonTam2Piece { color, prof, side } => Piece::NonTam2Piece {
+                        color: !color,
+                        prof,
+                        side,
+                    },
+                };
+                (coord, piece)
+            })
+            .collect(),
+        a_side_hop1zuo1: field.a_side_hop1zuo1.iter().copied().map(swap).collect(),
+        ia_side_hop1zuo1: field.ia_side_hop1zuo1.iter().copied().map(swap).collect(),
+    }
+}
+
+type FieldKey = (Vec<(Coord, Piece)>, Vec<NonTam2Piece>, Vec<NonTam2Piece>);
+
+fn field_key(field: &Field) -> FieldKey {
+    let mut board: Vec<_> = field
+        .board
+        .iter()
+        .map(|(&coord, &piece)| (coord, piece))
+        .collect();
+    board.sort_unstable();
+    let sorted_hop1zuo1 = |hop1zuo1: &[NonTam2Piece]| {
+        let mut v = hop1zuo1.to_vec();
+        sort_hop1zuo1(&mut v);
+        v
+    };
+    (
+        board,
+        sorted_hop1zuo1(&field.a_side_hop1zuo1),
+        sorted_hop1zuo1(&field.ia_side_hop1zuo1),
+    )
+}
+
+/// Checks whether the coordinate lies in the three rows where `side` starts the game.
+/// ／その座標が、`side` が対局開始時に駒を並べる三列の中にあるかを調べる。
+///
+/// These are the rows A, E and I for `ASide`, and the rows IA, AU and AI for `IASide`, as laid out by [`yhuap_initial_board`].
+/// ／`ASide` ならば A, E, I の三列、`IASide` ならば IA, AU, AI の三列であり、[`yhuap_initial_board`] の配置と一致する。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert!(is_in_home_rows(Coord(Row::I, Column::Z), Side::ASide));
+/// assert!(!is_in_home_rows(Coord(Row::I, Column::Z), Side::IASide));
+/// assert!(!is_in_home_rows(Coord(Row::O, Column::Z), Side::ASide));
+/// assert!(!is_in_home_rows(Coord(Row::O, Column::Z), Side::IASide));
+///
+/// // every piece starts in its own home rows
+/// for (coord, piece) in yhuap_initial_board() {
+///     if let Piece::NonTam2Piece { side, .. } = piece {
+///         assert!(is_in_home_rows(coord, side));
+///     }
+/// }
+/// ```
+#[must_use]
+pub const fn is_in_home_rows(Coord(row, _): Coord, side: Side) -> bool {
+    match side {
+        Side::ASide => matches!(row, Row::A | Row::E | Row::I),
+        Side::IASide => matches!(row, Row::IA | Row::AU | Row::AI),
+    }
+}
+
+/// Counts the Tam2s on the board.
+/// ／盤上の皇の個数を数える。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert_eq!(count_tam2(&board), 1);
+/// board.remove(&Coord(Row::O, Column::Z));
+/// assert_eq!(count_tam2(&board), 0);
+/// ```
+#[must_use]
+pub fn count_tam2(board: &Board) -> usize {
+    board.values().filter(|piece| piece.is_tam2()).count()
+}
+
+/// Checks that there is exactly one Tam2 on the board, as there should be throughout the game.
+/// ／盤上にちょうど一つの皇があるかを調べる。対局中は常にそうであるべきである。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert!(has_unique_tam2(&board));
+/// board.insert(Coord(Row::O, Column::K), Piece::Tam2);
+/// assert!(!has_unique_tam2(&board));
+/// ```
+#[must_use]
+pub fn has_unique_tam2(board: &Board) -> bool {
+    count_tam2(board) == 1
+}
+
+/// Returns the set of professions that the side has on the board.
+/// ／指定の陣営が盤上に持っている職種の集合を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert_eq!(professions_present(&board, Side::ASide).len(), 10);
+///
+/// board.retain(|_, piece| !piece.has_prof(Profession::Gua2));
+/// assert!(!professions_present(&board, Side::ASide).contains(&Profession::Gua2));
+/// ```
+#[must_use]
+pub fn professions_present(board: &Board, side: Side) -> HashSet<Profession> {
+    board
+        .values()
+        .filter_map(|piece| match *piece {
+            Piece::NonTam2Piece { prof, side: s, .. } if s == side => Some(prof),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the set of professions that the side has, either on the board or in its hop1zuo1.
+/// ／指定の陣営が盤上または手駒に持っている職種の集合を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// board.retain(|_, piece| !piece.has_prof(Profession::Gua2));
+/// let field = Field {
+///     board,
+///     a_side_hop1zuo1: vec![NonTam2Piece { color: Color::Kok1, prof: Profession::Gua2 }],
+///     ia_side_hop1zuo1: vec![],
+/// };
+/// assert!(professions_available(&field, Side::ASide).contains(&Profession::Gua2));
+/// assert!(!professions_available(&field, Side::IASide).contains(&Profession::Gua2));
+/// ```
+#[must_use]
+pub fn professions_available(field: &Field, side: Side) -> HashSet<Profession> {
+    let hop1zuo1 = match side {
+        Side::ASide => &field.a_side_hop1zuo1,
+        Side::IASide => &field.ia_side_hop1zuo1,
+    };
+    let mut ans = professions_present(&field.board, side);
+    ans.extend(hop1zuo1.iter().map(|p| p.prof));
+    ans
+}
+
+/// Returns every square reachable by sliding from `from` in the enabled directions, up to and including the first occupied square.
+/// ／`from` から有効な方向に走って到達できる全てのマスを返す。最初に駒があるマスも含む。
+///
+/// This is purely geometric: the side and color of the pieces are not looked at, so removing squares occupied by one's own pieces is left to the caller.
+/// The result is sorted in row-major order.
+/// ／純粋に幾何的な計算であり、駒の陣営や色は見ない。したがって、自駒のあるマスを除くのは呼び出し側の責任である。結果は行優先の順に整列されている。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let board = yhuap_initial_board();
+///
+/// // sliding orthogonally from the Tam2 stops at the pawns in rows I and AI
+/// let targets = slide_targets(&board, Coord(Row::O, Column::Z), false, true);
+/// assert!(targets.contains(&Coord(Row::I, Column::Z)));
+/// assert!(!targets.contains(&Coord(Row::E, Column::Z)));
+/// assert!(targets.contains(&Coord(Row::O, Column::K)));
+/// assert_eq!(targets.len(), 4 + 8);
+///
+/// assert!(slide_targets(&board, Coord(Row::O, Column::Z), false, false).is_empty());
+/// ```
+#[must_use]
+pub fn slide_targets(board: &Board, from: Coord, diagonal: bool, orthogonal: bool) -> Vec<Coord> {
+    let mut ans = vec![];
+    for dir in &Direction::all() {
+        let (dr, dc) = dir.delta();
+        let enabled = if dr != 0 && dc != 0 {
+            diagonal
+        } else {
+            orthogonal
+        };
+        if !enabled {
+            continue;
+        }
+        let mut coord = from;
+        while let Some(next) = step(coord, *dir) {
+            ans.push(next);
+            if board.contains_key(&next) {
+                break;
+            }
+            coord = next;
+        }
+    }
+    ans.sort();
+    ans
+}
+
+/// Reflects `point` through `center`, returning `None` if the result falls outside the board.
+/// ／`point` を `center` に関して点対称に移す。盤外に出るなら `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(
+///     reflect_over(Coord(Row::I, Column::N), Coord(Row::U, Column::T)),
+///     Some(Coord(Row::O, Column::Z))
+/// );
+/// assert_eq!(reflect_over(Coord(Row::E, Column::K), Coord(Row::A, Column::K)), None);
+/// ```
+#[must_use]
+pub fn reflect_over(point: Coord, center: Coord) -> Option<Coord> {
+    let (point_row, point_col) = indices(point);
+    let (center_row, center_col) = indices(center);
+    from_indices(2 * center_row - point_row, 2 * center_col - point_col)
+}
+
+/// Returns the square exactly between `a` and `b`, if they lie two squares apart along one of the eight directions.
+/// ／`a` と `b` が八方向のいずれかに沿って2マス離れているなら、ちょうどその間にあるマスを返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(
+///     midpoint(Coord(Row::I, Column::N), Coord(Row::O, Column::Z)),
+///     Some(Coord(Row::U, Column::T))
+/// );
+/// assert_eq!(midpoint(Coord(Row::A, Column::K), Coord(Row::A, Column::T)), None);
+/// assert_eq!(midpoint(Coord(Row::A, Column::K), Coord(Row::E, Column::N)), None);
+/// ```
+#[must_use]
+pub fn midpoint(a: Coord, b: Coord) -> Option<Coord> {
+    let dir = direction_between(a, b)?;
+    let mid = step(a, dir)?;
+    if step(mid, dir)? == b {
+        Some(mid)
+    } else {
+        None
+    }
+}
+
+/// Finds the square of Tam2. Returns `None` unless there is exactly one Tam2 on the board.
+/// ／皇のあるマスを探す。盤上の皇がちょうど一つでなければ `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert_eq!(tam2_position(&board), Some(Coord(Row::O, Column::Z)));
+/// board.remove(&Coord(Row::O, Column::Z));
+/// assert_eq!(tam2_position(&board), None);
+/// ```
+#[must_use]
+pub fn tam2_position(board: &Board) -> Option<Coord> {
+    let mut tam2s = board
+        .iter()
+        .filter(|(_, piece)| piece.is_tam2())
+        .map(|(&coord, _)| coord);
+    let coord = tam2s.next()?;
+    if tam2s.next().is_some() {
+        return None;
+    }
+    Some(coord)
+}
+
+/// Checks whether the square is one of the eight squares surrounding Tam2.
+/// ／マスが、皇を囲む8マスのいずれかであるかを調べる。
+///
+/// Returns `false` if [`tam2_position`] finds no Tam2, which includes the case where there is more than one.
+/// The square of Tam2 itself is not adjacent to Tam2.
+/// ／[`tam2_position`] が皇を見つけられない場合（皇が複数ある場合を含む）は `false` を返す。皇のあるマス自体は皇に隣接していない。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert!(is_adjacent_to_tam2(&board, Coord(Row::Y, Column::X)));
+/// assert!(!is_adjacent_to_tam2(&board, Coord(Row::O, Column::Z)));
+/// assert!(!is_adjacent_to_tam2(&board, Coord(Row::AI, Column::Z)));
+///
+/// board.remove(&Coord(Row::O, Column::Z));
+/// assert!(!is_adjacent_to_tam2(&board, Coord(Row::Y, Column::X)));
+/// ```
+#[must_use]
+pub fn is_adjacent_to_tam2(board: &Board, coord: Coord) -> bool {
+    tam2_position(board).is_some_and(|tam2| distance(tam2, coord) == 1)
+}
+
+/// Counts the side's Kauk2s in each column, indexed in the order from column K to column P.
+/// ／各筋にある指定の陣営の兵を数える。添字は K 筋から P 筋の順である。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let board = yhuap_initial_board();
+/// assert_eq!(pawns_per_column(&board, Side::ASide), [1, 1, 1, 1, 0, 1, 1, 1, 1]);
+/// ```
+#[must_use]
+pub fn pawns_per_column(board: &Board, side: Side) -> [u8; 9] {
+    let mut ans = [0; 9];
+    for (&Coord(_, col), piece) in board {
+        if piece.has_prof(Profession::Kauk2) && piece.has_side(side) {
+            ans[column_index(col)] += 1;
+        }
+    }
+    ans
+}
+
+/// Returns every square in the rectangle with the two given squares as opposite corners, both inclusive, in row-major order.
+/// ／与えられた二マスを対角の頂点とする長方形（両端を含む）に含まれる全てのマスを、行優先の順で返す。
+///
+/// The corners may be given in any order.
+/// ／頂点はどの順で与えてもよい。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let rect = coords_in_rect(Coord(Row::U, Column::T), Coord(Row::Y, Column::X));
+/// assert_eq!(rect.len(), 9);
+/// assert_eq!(rect[0], Coord(Row::U, Column::T));
+/// assert_eq!(rect[8], Coord(Row::Y, Column::X));
+///
+/// // reversed corners give the same result
+/// assert_eq!(coords_in_rect(Coord(Row::Y, Column::T), Coord(Row::U, Column::X)), rect);
+/// ```
+#[must_use]
+pub fn coords_in_rect(top_left: Coord, bottom_right: Coord) -> Vec<Coord> {
+    let (row1, col1) = (row_index(top_left.0), column_index(top_left.1));
+    let (row2, col2) = (row_index(bottom_right.0), column_index(bottom_right.1));
+    let mut ans = vec![];
+    for &row in &ROWS[row1.min(row2)..=row1.max(row2)] {
+        for &col in &COLUMNS[col1.min(col2)..=col1.max(col2)] {
+            ans.push(Coord(row, col));
+        }
+    }
+    ans
+}
+
+/// Returns the empty squares, in row-major order, optionally excluding the tam2 nua2 (see [`is_water`]).
+/// ／空いているマスを行優先の順で返す。皇水（[`is_water`] を参照）を除くこともできる。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let board = yhuap_initial_board();
+/// assert_eq!(legal_drop_targets(&board, false).len(), 81 - 49);
+/// assert_eq!(legal_drop_targets(&board, true).len(), 81 - 49 - 6);
+/// ```
+#[must_use]
+pub fn legal_drop_targets(board: &Board, restrict_water: bool) -> Vec<Coord> {
+    (0..81)
+        .filter_map(index_to_coord)
+        .filter(|coord| !board.contains_key(coord))
+        .filter(|coord| !(restrict_water && is_water(*coord)))
+        .collect()
+}
+
+/// Rebuilds a board by applying `f` to each occupied square, dropping the squares for which `f` returns `None`.
+/// ／駒のある各マスに `f` を適用して盤を作り直す。`f` が `None` を返したマスは空になる。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// let board = yhuap_initial_board();
+///
+/// // strip all of IASide's pieces
+/// let stripped = map_pieces(&board, |_, piece| if piece.has_side(Side::IASide) { None } else { Some(piece) });
+/// assert_eq!(stripped.len(), 25);
+///
+/// // recolor everything red
+/// let red = map_pieces(&board, |_, piece| match piece {
+///     Piece::NonTam2Piece { prof, side, .. } => Some(Piece::NonTam2Piece { color: Color::Kok1, prof, side }),
+///     Piece::Tam2 => Some(Piece::Tam2),
+/// });
+/// assert!(red.values().all(|p| !p.has_color(Color::Huok2)));
+/// ```
+#[must_use]
+pub fn map_pieces(board: &Board, f: impl Fn(Coord, Piece) -> Option<Piece>) -> Board {
+    board
+        .iter()
+        .filter_map(|(&coord, &piece)| Some((coord, f(coord, piece)?)))
+        .collect()
+}
+
+/// Returns which half of the board the square is in, or `None` for the center row O.
+/// ／マスが盤のどちら側の半分にあるかを返す。中央の O 行なら `None`。
+///
+/// The rows A, E, I and U are the half of `ASide`, and the rows Y, AI, AU and IA are the half of `IASide`.
+/// ／A, E, I, U の四行が `ASide` の半分、Y, AI, AU, IA の四行が `IASide` の半分である。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(board_half(Coord(Row::U, Column::K)), Some(Side::ASide));
+/// assert_eq!(board_half(Coord(Row::Y, Column::K)), Some(Side::IASide));
+/// assert_eq!(board_half(Coord(Row::O, Column::K)), None);
+/// ```
+#[must_use]
+pub const fn board_half(Coord(row, _): Coord) -> Option<Side> {
+    match row {
+        Row::A | Row::E | Row::I | Row::U => Some(Side::ASide),
+        Row::O => None,
+        Row::Y | Row::AI | Row::AU | Row::IA => Some(Side::IASide),
+    }
+}
+
+/// Checks whether the two squares are a knight's move apart, that is, one step along one axis and two along the other.
+/// ／2マスが桂馬跳び（一方の軸に1、もう一方の軸に2）の位置関係にあるかどうかを調べる。
+///
+/// The standard cetkaik has no such move; this is purely geometric and meant for experimenting with variants.
+/// ／標準の机戦にはこのような動きはない。純粋に幾何的な判定であり、変種ルールの試作用である。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::{is_knight_move, Coord};
+/// use cetkaik_core::absolute::Row::*;
+/// use cetkaik_core::absolute::Column::*;
+///
+/// assert!(is_knight_move(Coord(A, K), Coord(E, N))); // one row, two columns
+/// assert!(is_knight_move(Coord(A, K), Coord(I, L))); // two rows, one column
+/// assert!(!is_knight_move(Coord(A, K), Coord(I, N)));
+/// ```
+#[must_use]
+pub const fn is_knight_move(a: Coord, b: Coord) -> bool {
+    let Coord(row_a, col_a) = a;
+    let Coord(row_b, col_b) = b;
+    let row_diff = row_index(row_a).abs_diff(row_index(row_b));
+    let col_diff = column_index(col_a).abs_diff(column_index(col_b));
+    matches!((row_diff, col_diff), (1, 2) | (2, 1))
+}
+
+/// Puts a captured piece into the hop1zuo1 of the capturing side.
+/// ／取った駒を、取った側の手駒に加える。
+///
+/// Capturing changes who owns the piece, not its color: colors are not tied to sides in cetkaik,
+/// so the piece joins `capturer`'s hop1zuo1 with its color unchanged, and it belongs to `capturer` from then on
+/// (for example, when dropped back onto the board).
+/// ／駒を取ると所有者が変わるが、色は変わらない。机戦では色と陣営は無関係なので、
+/// 駒は色をそのままに `capturer` の手駒に加わり、以後（たとえば盤に打ち直されるとき）は `capturer` に属する。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+/// use cetkaik_core::*;
+///
+/// let mut field = Field::default();
+/// let captured = NonTam2Piece { color: Color::Kok1, prof: Profession::Kauk2 };
+/// capture_into_hand(&mut field, captured, Side::IASide);
+/// assert_eq!(field.ia_side_hop1zuo1, vec![captured]);
+/// assert!(field.a_side_hop1zuo1.is_empty());
+/// ```
+pub fn capture_into_hand(field: &mut Field, piece: NonTam2Piece, capturer: Side) {
+    let NonTam2Piece { color, prof } = piece;
+    field.insert_nontam_piece_into_hop1zuo1(color, prof, capturer);
+}
+
+/// Calculates how many steps apart two rows are. This is the one-dimensional counterpart of [`distance`](./fn.distance.html).
+/// ／2つの行が何マス離れているかを計算する。[`distance`](./fn.distance.html) の一次元版である。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(row_distance(Row::A, Row::IA), 8);
+/// assert_eq!(row_distance(Row::AI, Row::O), 2);
+/// assert_eq!(
+///     row_distance(Row::E, Row::AU),
+///     distance(Coord(Row::E, Column::Z), Coord(Row::AU, Column::Z))
+/// );
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub const fn row_distance(a: Row, b: Row) -> i32 {
+    // both indices are less than 9, so the cast is lossless
+    row_index(a).abs_diff(row_index(b)) as i32
+}
+
+/// Calculates how many steps apart two columns are. This is the one-dimensional counterpart of [`distance`](./fn.distance.html).
+/// ／2つの列が何マス離れているかを計算する。[`distance`](./fn.distance.html) の一次元版である。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(column_distance(Column::K, Column::P), 8);
+/// assert_eq!(column_distance(Column::X, Column::T), 2);
+/// assert_eq!(
+///     column_distance(Column::L, Column::M),
+///     distance(Coord(Row::O, Column::L), Coord(Row::O, Column::M))
+/// );
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub const fn column_distance(a: Column, b: Column) -> i32 {
+    // both indices are less than 9, so the cast is lossless
+    column_index(a).abs_diff(column_index(b)) as i32
+}
+
+/// Checks whether two fields have the same board, ignoring the hop1zuo1s entirely.
+/// ／2つのフィールドの盤が同一かどうかを調べる。手駒は一切考慮しない。
+///
+/// If this returns `true` for two different fields, they differ only in the hop1zuo1s.
+/// ／異なる2つのフィールドに対してこれが `true` を返すなら、両者の違いは手駒だけである。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+/// use cetkaik_core::*;
+///
+/// let before = yhuap_initial_field();
+/// let mut after = before.clone();
+/// after.insert_nontam_piece_into_hop1zuo1(Color::Kok1, Profession::Io, Side::ASide);
+/// assert!(board_unchanged(&before, &after));
+///
+/// after.board.remove(&Coord(Row::A, Column::K));
+/// assert!(!board_unchanged(&before, &after));
+/// ```
+#[must_use]
+pub fn board_unchanged(a: &Field, b: &Field) -> bool {
+    a.board == b.board
+}
+
+/// Describes how a piece moves, for use with [`attacked_squares`](./fn.attacked_squares.html).
+/// ／駒の動き方を表す。[`attacked_squares`](./fn.attacked_squares.html) で用いる。
+///
+/// Each flag enables a kind of move in all four orthogonal or all four diagonal directions alike.
+/// ／各フラグは、縦横四方向すべて、または斜め四方向すべてにおいて一様にその種の動きを可能にする。
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct MovementCaps {
+    /// moves any distance orthogonally, up to the first occupied square／縦横に、最初に駒があるマスまで何マスでも進む
+    pub slides_orthogonally: bool,
+
+    /// moves any distance diagonally, up to the first occupied square／斜めに、最初に駒があるマスまで何マスでも進む
+    pub slides_diagonally: bool,
+
+    /// moves one square orthogonally／縦横に1マス進む
+    pub steps_orthogonally: bool,
+
+    /// moves one square diagonally／斜めに1マス進む
+    pub steps_diagonally: bool,
+
+    /// jumps exactly two squares diagonally, regardless of what lies between／間に何があっても斜めにちょうど2マス跳ぶ
+    pub jumps_diagonally: bool,
+}
+
+impl MovementCaps {
+    /// Returns caps covering every move of the profession outside tam2 hue under the y1 huap1 (the standardized rule).
+    /// ／官定において、皇処の外でのその職種の全ての動きを含む能力を返す。
+    ///
+    /// Since the caps cannot restrict a move to some of the directions, this over-approximates pieces such as Nuak1 and Kauk2,
+    /// which only move forward; the result is thus the squares that *may* be attacked.
+    /// ／能力は動きを一部の方向に限ることができないので、前にしか進めない Nuak1 や Kauk2 などについては実際より広くなる。
+    /// したがって、得られるのは利きが*ありうる*マスである。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::absolute::*;
+    /// use cetkaik_core::*;
+    ///
+    /// assert!(MovementCaps::covering(Profession::Gua2).slides_orthogonally);
+    /// assert!(MovementCaps::covering(Profession::Maun1).jumps_diagonally);
+    /// assert!(!MovementCaps::covering(Profession::Dau2).steps_orthogonally);
+    /// ```
+    #[must_use]
+    pub const fn covering(prof: Profession) -> Self {
+        Self {
+            slides_orthogonally: prof.slides_orthogonally(),
+            slides_diagonally: prof.slides_diagonally(),
+            steps_orthogonally: matches!(
+                prof,
+                Profession::Kauk2
+                    | Profession::Kua2
+                    | Profession::Tuk2
+                    | Profession::Uai1
+                    | Profession::Io
+            ),
+            steps_diagonally: matches!(prof, Profession::Dau2 | Profession::Uai1 | Profession::Io),
+            jumps_diagonally: matches!(prof, Profession::Maun1),
+        }
+    }
+}
+
+/// Returns every square attacked by any of the pieces of `side`, given the movement of each profession.
+/// ／`side` の駒のいずれかが利いている全てのマスを、各職種の動きを与えて求める。
+///
+/// Squares occupied by pieces of either side are included, as long as a piece of `side` reaches them.
+/// To tell whether a king is in check, look up the king's square in the attack map of the opponent.
+/// ／どちらの陣営の駒があるマスも、`side` の駒が届く限り含む。王手がかかっているかどうかは、相手の利きに王のマスが含まれるかで判定する。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+/// use cetkaik_core::*;
+///
+/// let board = maplit::hashmap! {
+///     Coord(Row::A, Column::Z) => Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Gua2, side: Side::ASide },
+///     Coord(Row::O, Column::Z) => Piece::Tam2,
+///     Coord(Row::AU, Column::Z) => Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Io, side: Side::IASide },
+///     Coord(Row::AU, Column::P) => Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Io, side: Side::ASide },
+/// };
+///
+/// let attacked = attacked_squares(&board, Side::ASide, MovementCaps::covering);
+/// assert!(attacked.contains(Coord(Row::O, Column::Z))); // the rook is stopped by the Tam2
+/// assert!(!attacked.contains(Coord(Row::AU, Column::Z))); // so the king is not in check
+/// assert!(attacked.contains(Coord(Row::IA, Column::M))); // but the other king attacks its neighbours
+/// ```
+#[must_use]
+pub fn attacked_squares(
+    board: &Board,
+    side: Side,
+    caps: impl Fn(Profession) -> MovementCaps,
+) -> CoordSet {
+    let mut ans = CoordSet::new();
+    for (&from, &piece) in board {
+        let prof = match piece {
+            Piece::NonTam2Piece { prof, side: s, .. } if s == side => prof,
+            _ => continue,
+        };
+        let c = caps(prof);
+        for target in slide_targets(board, from, c.slides_diagonally, c.slides_orthogonally) {
+            ans.insert(target);
+        }
+        for dir in Direction::all() {
+            let (dr, dc) = dir.delta();
+            let diagonal = dr != 0 && dc != 0;
+            let steps = if diagonal {
+                c.steps_diagonally
+            } else {
+                c.steps_orthogonally
+            };
+            if steps {
+                if let Some(target) = step(from, dir) {
+                    ans.insert(target);
+                }
+            }
+            if diagonal && c.jumps_diagonally {
+                if let Some(target) = step(from, dir).and_then(|mid| step(mid, dir)) {
+                    ans.insert(target);
+                }
+            }
+        }
+    }
+    ans
+}
+
+/// Checks whether none of the nine squares in the row is occupied.
+/// ／行の9マスのいずれにも駒がないかどうかを調べる。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let board = yhuap_initial_board();
+/// assert!(is_row_empty(&board, Row::U));
+/// assert!(!is_row_empty(&board, Row::O)); // the Tam2
+/// ```
+#[must_use]
+pub fn is_row_empty(board: &Board, row: Row) -> bool {
+    row_coords(row)
+        .iter()
+        .all(|coord| !board.contains_key(coord))
+}
+
+/// Checks whether none of the nine squares in the column is occupied.
+/// ／列の9マスのいずれにも駒がないかどうかを調べる。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut board = yhuap_initial_board();
+/// assert!(!is_column_empty(&board, Column::K));
+/// board.retain(|Coord(_, col), _| *col != Column::K);
+/// assert!(is_column_empty(&board, Column::K));
+/// ```
+#[must_use]
+pub fn is_column_empty(board: &Board, column: Column) -> bool {
+    column_coords(column)
+        .iter()
+        .all(|coord| !board.contains_key(coord))
+}
+
+/// Returns every square that the side occupies in [`yhuap_initial_board`], in row-major order.
+/// ／[`yhuap_initial_board`] においてその陣営の駒がある全てのマスを、行優先の順に返す。
+///
+/// The square of the Tam2, which belongs to neither side, is not included.
+/// ／どちらの陣営にも属さない皇のマスは含まない。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let squares = initial_squares(Side::IASide);
+/// assert_eq!(squares.len(), 24);
+/// assert!(squares.contains(&Coord(Row::IA, Column::K)));
+/// assert!(!squares.contains(&Coord(Row::O, Column::Z)));
+/// ```
+#[must_use]
+pub fn initial_squares(side: Side) -> Vec<Coord> {
+    let mut ans: Vec<Coord> = yhuap_initial_board()
+        .into_iter()
+        .filter(|(_, piece)| piece.has_side(side))
+        .map(|(coord, _)| coord)
+        .collect();
+    ans.sort();
+    ans
+}
+
+/// Annotates squares with arbitrary data, such as highlights or threat counts, for layering onto a board.
+/// ／強調表示や利きの数など、任意のデータをマスに付与する。盤に重ねて表示するためのもの。
+/// # Examples
+/// ```
+/// use cetkaik_core::absolute::*;
+///
+/// let mut threats = Overlay::new();
+/// threats.set(Coord(Row::U, Column::Z), 2);
+/// threats.set(Coord(Row::U, Column::K), 1);
+/// assert_eq!(threats.get(Coord(Row::U, Column::Z)), Some(&2));
+/// assert_eq!(threats.iter().count(), 2);
+///
+/// let text = threats.render_with(&yhuap_initial_board(), |n| char::from(b'0' + *n));
+/// assert_eq!(text.lines().nth(3), Some("1・・・2・・・・"));
+/// assert_eq!(text.lines().nth(4), Some("・・・・皇・・・・"));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Overlay<T>(pub HashMap<Coord, T>);
+
+impl<T> Default for Overlay<T> {
+    fn default() -> Self {
+        Overlay(HashMap::new())
+    }
+}
+
+impl<T> Overlay<T> {
+    /// Creates an overlay with no annotation.
+    /// ／何も付与されていないオーバーレイを作る。
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the annotation on the square, if any.
+    /// ／マスに付与されたデータがあれば返す。
+    #[must_use]
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.0.get(&coord)
+    }
+
+    /// Annotates the square, returning the annotation it replaced, if any.
+    /// ／マスにデータを付与する。置き換えられたデータがあれば返す。
+    pub fn set(&mut self, coord: Coord, value: T) -> Option<T> {
+        self.0.insert(coord, value)
+    }
+
+    /// Iterates over the annotated squares. The order is unspecified.
+    /// ／データが付与されたマスを列挙する。順番は不定。
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.0.iter().map(|(&coord, value)| (coord, value))
+    }
+
+    /// Renders the board as nine lines from row A to row IA, each from column K to column P, with the overlay on top.
+    /// ／盤を、A行からIA行までの9行、各行K列からP列の順に描画し、その上にオーバーレイを重ねる。
+    ///
+    /// An annotated square is shown as `f` of the annotation; otherwise a piece is shown as its profession (`皇` for Tam2), and an empty square as `・`.
+    /// ／データが付与されたマスはそのデータに `f` を適用した文字で表示する。そうでなければ、駒はその職種（皇は `皇`）で、空きマスは `・` で表示する。
+    pub fn render_with(&self, board: &Board, f: impl Fn(&T) -> char) -> String {
+        let mut ans = String::new();
+        for row in ROWS {
+            for col in COLUMNS {
+                let coord = Coord(row, col);
+                ans.push(match (self.0.get(&coord), board.get(&coord)) {
+                    (Some(value), _) => f(value),
+                    (None, Some(Piece::Tam2)) => '皇',
+                    (None, Some(Piece::NonTam2Piece { prof, .. })) => {
+                        super::serialize_prof(*prof).chars().next().unwrap_or('?')
+                    }
+                    (None, None) => '・',
+                });
+            }
+            ans.push('\n');
+        }
+        ans
+    }
+}
@@ -1,11 +1,15 @@
 //! Core data types and whatnot for cetkaik, a board game. See <https://sites.google.com/view/cet2kaik/the-standardized-rule-in-english> for more context.
 //! ／机戦（セットカイク）のための基本的なデータ型など。
 #![warn(clippy::pedantic, clippy::nursery, missing_docs)]
-#![allow(clippy::non_ascii_literal, clippy::use_self, clippy::upper_case_acronyms)]
+#![allow(
+    clippy::non_ascii_literal,
+    clippy::use_self,
+    clippy::upper_case_acronyms
+)]
 #[macro_use]
 extern crate maplit;
 /// Denotes the color of a piece／駒の色を表す。
-#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Hash)]
 pub enum Color {
     /// Red, 赤
     Kok1,
@@ -14,6 +18,55 @@ pub enum Color {
     Huok2,
 }
 
+impl std::ops::Not for Color {
+    type Output = Color;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Color::Kok1 => Color::Huok2,
+            Color::Huok2 => Color::Kok1,
+        }
+    }
+}
+
+impl Color {
+    /// Returns the compact numeric code of the color: `0` for `Kok1` and `1` for `Huok2`.
+    /// ／色を表す数値コードを返す。`Kok1` は `0`、`Huok2` は `1` である。
+    ///
+    /// This code is what serde uses for non-human-readable formats; human-readable formats get `"赤"` or `"黒"`.
+    /// Deserialization accepts both in human-readable formats.
+    /// ／serde は、人間が読むための形式でないときにこのコードを用いる。人間が読むための形式では `"赤"` や `"黒"` となる。
+    /// 人間が読むための形式からのデシリアライズではどちらも受け付ける。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use serde_test::{assert_tokens, Configure, Token};
+    ///
+    /// assert_tokens(&Color::Huok2.compact(), &[Token::U8(1)]);
+    /// assert_tokens(&Color::Huok2.readable(), &[Token::Str("黒")]);
+    /// assert_eq!(serde_json::from_str::<Color>("1").unwrap(), Color::Huok2);
+    /// assert_eq!(serde_json::from_str::<Color>("\"black\"").unwrap(), Color::Huok2);
+    /// ```
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Color::Kok1 => 0,
+            Color::Huok2 => 1,
+        }
+    }
+
+    /// Converts the numeric code given by [`to_u8`](#method.to_u8) back to the color.
+    /// ／[`to_u8`](#method.to_u8) の数値コードを色に戻す。
+    #[must_use]
+    pub const fn from_u8(code: u8) -> Option<Color> {
+        match code {
+            0 => Some(Color::Kok1),
+            1 => Some(Color::Huok2),
+            _ => None,
+        }
+    }
+}
+
 /// Serializes [`Color`](./enum.Color.html).／[`Color`](./enum.Color.html)を文字列に変換する。
 /// # Examples
 /// ```
@@ -32,7 +85,25 @@ pub const fn serialize_color(color: Color) -> &'static str {
 }
 
 /// Denotes the profession of a piece／駒の職業を表す。
-#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+///
+/// Professions are ordered as they are listed in the y1 huap1 (the standardized rule), which is also the order of declaration.
+/// ／職種は官定に列挙される順に並ぶ。これは宣言順でもある。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use std::collections::BTreeMap;
+///
+/// let mut all = Profession::all();
+/// all.sort();
+/// assert_eq!(all, Profession::all());
+///
+/// let counts: BTreeMap<Profession, u8> = Profession::all()
+///     .iter()
+///     .map(|&p| (p, p.initial_count_per_side()))
+///     .collect();
+/// assert_eq!(counts.keys().next(), Some(&Profession::Nuak1));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Hash)]
 pub enum Profession {
     /// Vessel, 船, felkana
     Nuak1,
@@ -65,6 +136,54 @@ pub enum Profession {
     Io,
 }
 
+/// Groups the professions by how they move outside tam2 hue, e.g. for the menus of a piece palette.
+/// ／皇処の外での動き方によって職種を分類する。駒を選ぶメニューなどに用いる。
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Hash)]
+pub enum ProfessionFamily {
+    /// Moves any distance in all four orthogonal or all four diagonal directions: Gua2 and Kaun1.
+    /// ／縦横四方向、または斜め四方向に何マスでも進める。弓と車。
+    Slider,
+
+    /// Moves any distance only in some directions: Nuak1, Kua2 and Tuk2.
+    /// ／一部の方向にのみ何マスでも進める。船、筆、巫。
+    PartialSlider,
+
+    /// Moves exactly one square: Kauk2, Dau2, Uai1 and Io.
+    /// ／ちょうど1マス進む。兵、虎、将、王。
+    Stepper,
+
+    /// Jumps to a square that is not adjacent: Maun1.
+    /// ／隣接しないマスへ跳ぶ。馬。
+    Jumper,
+}
+
+impl ProfessionFamily {
+    /// Returns the professions in the family, in the order of declaration of [`Profession`](./enum.Profession.html).
+    /// ／系統に属する職種を、[`Profession`](./enum.Profession.html) の宣言順で返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert_eq!(ProfessionFamily::Slider.members(), &[Profession::Gua2, Profession::Kaun1]);
+    /// ```
+    #[must_use]
+    pub const fn members(self) -> &'static [Profession] {
+        match self {
+            ProfessionFamily::Slider => &[Profession::Gua2, Profession::Kaun1],
+            ProfessionFamily::PartialSlider => {
+                &[Profession::Nuak1, Profession::Kua2, Profession::Tuk2]
+            }
+            ProfessionFamily::Stepper => &[
+                Profession::Kauk2,
+                Profession::Dau2,
+                Profession::Uai1,
+                Profession::Io,
+            ],
+            ProfessionFamily::Jumper => &[Profession::Maun1],
+        }
+    }
+}
+
 /// Serializes [`Profession`](./enum.Profession.html).／[`Profession`](./enum.Profession.html)を文字列にする。
 /// # Examples
 /// ```
@@ -90,9 +209,351 @@ pub const fn serialize_prof(prof: Profession) -> &'static str {
     }
 }
 
+/// Gives a default value of a piece of the profession, for a quick static evaluation.
+/// ／簡易的な静的評価のための、職種ごとの駒の既定の価値を与える。
+///
+/// There is no established table of piece values for cetkaik that could be cited, so the numbers below are a heuristic
+/// derived from the mobility flags on [`Profession`](./enum.Profession.html) (see `slides_orthogonally`):
+/// full sliders are worth 5, half sliders 4, the one-directional slider Nuak1 and the two king-like steppers 3,
+/// the remaining steppers and jumpers 2, and Kauk2 1. Pass your own closure to `material_difference` if you want other weights.
+/// ／引用できるような確立された駒の価値の表は存在しないので、以下の値は [`Profession`](./enum.Profession.html) の移動フラグ
+/// （`slides_orthogonally` を参照）から導いた経験則である。全方向に走る駒は5、半分だけ走る駒は4、
+/// 一方向にのみ走る船と王のように動く二種は3、残りの一歩動く駒と跳ぶ駒は2、兵は1。
+/// 別の重みを使いたい場合は `material_difference` に独自のクロージャを渡すこと。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::absolute::*;
+///
+/// assert_eq!(default_piece_value(Profession::Gua2), 5);
+///
+/// let field = Field {
+///     board: yhuap_initial_board(),
+///     a_side_hop1zuo1: vec![],
+///     ia_side_hop1zuo1: vec![NonTam2Piece { color: Color::Kok1, prof: Profession::Kaun1 }],
+/// };
+/// assert_eq!(field.material_difference(default_piece_value), 5);
+/// ```
+#[must_use]
+pub const fn default_piece_value(prof: Profession) -> i32 {
+    match prof {
+        Profession::Gua2 | Profession::Kaun1 => 5,
+        Profession::Kua2 | Profession::Tuk2 => 4,
+        Profession::Nuak1 | Profession::Uai1 | Profession::Io => 3,
+        Profession::Dau2 | Profession::Maun1 => 2,
+        Profession::Kauk2 => 1,
+    }
+}
+
+impl Profession {
+    /// Returns a single-letter code of the profession, for use in compact notations of moves.
+    /// ／棋譜などの簡潔な表記のために、職種を一文字で表す。
+    ///
+    /// The letter is the lowercase initial of the English name, so that it never collides with the uppercase letters used in coordinates:
+    /// ／英語名の頭文字の小文字を用いる。座標に使われる大文字とは衝突しない。
+    ///
+    /// | profession | letter |
+    /// |---|---|
+    /// | Nuak1 (Vessel, 船) | `v` |
+    /// | Kauk2 (Pawn, 兵) | `p` |
+    /// | Gua2 (Rook, 弓) | `r` |
+    /// | Kaun1 (Bishop, 車) | `b` |
+    /// | Dau2 (Tiger, 虎) | `t` |
+    /// | Maun1 (Horse, 馬) | `h` |
+    /// | Kua2 (Clerk, 筆) | `c` |
+    /// | Tuk2 (Shaman, 巫) | `s` |
+    /// | Uai1 (General, 将) | `g` |
+    /// | Io (King, 王) | `k` |
+    ///
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert_eq!(Profession::Gua2.notation_letter(), 'r');
+    /// assert_eq!(Profession::from_notation_letter('r'), Some(Profession::Gua2));
+    /// assert_eq!(Profession::from_notation_letter('R'), None);
+    ///
+    /// use Profession::*;
+    /// for prof in [Nuak1, Kauk2, Gua2, Kaun1, Dau2, Maun1, Kua2, Tuk2, Uai1, Io] {
+    ///     assert_eq!(Profession::from_notation_letter(prof.notation_letter()), Some(prof));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn notation_letter(self) -> char {
+        match self {
+            Profession::Nuak1 => 'v',
+            Profession::Kauk2 => 'p',
+            Profession::Gua2 => 'r',
+            Profession::Kaun1 => 'b',
+            Profession::Dau2 => 't',
+            Profession::Maun1 => 'h',
+            Profession::Kua2 => 'c',
+            Profession::Tuk2 => 's',
+            Profession::Uai1 => 'g',
+            Profession::Io => 'k',
+        }
+    }
+
+    /// Returns all the professions, in the order of declaration.
+    /// ／全ての職種を宣言順に返す。
+    ///
+    /// Iterating over this instead of matching on every variant keeps code working should a profession ever be added.
+    /// ／全ての列挙子で `match` する代わりにこれを反復すれば、職種が追加されてもコードが壊れない。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// let total: u8 = Profession::all().iter().map(|p| p.initial_count_per_side()).sum();
+    /// assert_eq!(total, 24);
+    ///
+    /// for (i, prof) in Profession::all().iter().enumerate() {
+    ///     assert_eq!(usize::from(prof.to_u8()), i);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn all() -> [Profession; 10] {
+        [
+            Profession::Nuak1,
+            Profession::Kauk2,
+            Profession::Gua2,
+            Profession::Kaun1,
+            Profession::Dau2,
+            Profession::Maun1,
+            Profession::Kua2,
+            Profession::Tuk2,
+            Profession::Uai1,
+            Profession::Io,
+        ]
+    }
+
+    /// Returns the profession at the given index of [`all`](#method.all), or `None` if out of range.
+    /// ／[`all`](#method.all) の指定の位置にある職種を返す。範囲外なら `None`。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert_eq!(Profession::try_from_index(0), Some(Profession::Nuak1));
+    /// assert_eq!(Profession::try_from_index(10), None);
+    /// ```
+    #[must_use]
+    pub const fn try_from_index(index: u8) -> Option<Profession> {
+        Profession::from_u8(index)
+    }
+
+    /// Returns the compact numeric code of the profession, which follows the order of declaration:
+    /// `0` for `Nuak1`, `1` for `Kauk2`, ..., `9` for `Io`.
+    /// ／職種を表す数値コードを返す。宣言順に従い、`Nuak1` が `0`、`Kauk2` が `1`、……、`Io` が `9` である。
+    ///
+    /// As with [`Color::to_u8`](./enum.Color.html#method.to_u8), serde uses this code for non-human-readable formats.
+    /// ／[`Color::to_u8`](./enum.Color.html#method.to_u8) と同様に、serde は人間が読むための形式でないときにこのコードを用いる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use serde_test::{assert_tokens, Configure, Token};
+    ///
+    /// assert_tokens(&Profession::Io.compact(), &[Token::U8(9)]);
+    /// assert_tokens(&Profession::Io.readable(), &[Token::Str("王")]);
+    /// ```
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Profession::Nuak1 => 0,
+            Profession::Kauk2 => 1,
+            Profession::Gua2 => 2,
+            Profession::Kaun1 => 3,
+            Profession::Dau2 => 4,
+            Profession::Maun1 => 5,
+            Profession::Kua2 => 6,
+            Profession::Tuk2 => 7,
+            Profession::Uai1 => 8,
+            Profession::Io => 9,
+        }
+    }
+
+    /// Converts the numeric code given by [`to_u8`](#method.to_u8) back to the profession.
+    /// ／[`to_u8`](#method.to_u8) の数値コードを職種に戻す。
+    #[must_use]
+    pub const fn from_u8(code: u8) -> Option<Profession> {
+        match code {
+            0 => Some(Profession::Nuak1),
+            1 => Some(Profession::Kauk2),
+            2 => Some(Profession::Gua2),
+            3 => Some(Profession::Kaun1),
+            4 => Some(Profession::Dau2),
+            5 => Some(Profession::Maun1),
+            6 => Some(Profession::Kua2),
+            7 => Some(Profession::Tuk2),
+            8 => Some(Profession::Uai1),
+            9 => Some(Profession::Io),
+            _ => None,
+        }
+    }
+
+    /// Returns how many pieces of the profession each side has at the start of the game, according to the y1 huap1 (the standardized rule).
+    /// ／官定において、各陣営が初期状態でその職種の駒をいくつ持つかを返す。
+    ///
+    /// Each side starts with one Nuak1, eight Kauk2, one Io, and two each of the other professions (one red and one black), 24 in total.
+    /// ／各陣営は、船を一つ、兵を八つ、王を一つ、そしてそれ以外の職種を二つずつ（赤と黒を一つずつ）持ち、合計24個である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::absolute::*;
+    ///
+    /// assert_eq!(Profession::Kauk2.initial_count_per_side(), 8);
+    ///
+    /// // consistent with the initial board
+    /// let board = yhuap_initial_board();
+    /// for prof in [Profession::Nuak1, Profession::Kauk2, Profession::Gua2, Profession::Io] {
+    ///     let count = board
+    ///         .values()
+    ///         .filter(|p| p.has_prof(prof) && p.has_side(Side::ASide))
+    ///         .count();
+    ///     assert_eq!(count, usize::from(prof.initial_count_per_side()));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn initial_count_per_side(self) -> u8 {
+        match self {
+            Profession::Nuak1 | Profession::Io => 1,
+            Profession::Kauk2 => 8,
+            Profession::Gua2
+            | Profession::Kaun1
+            | Profession::Dau2
+            | Profession::Maun1
+            | Profession::Kua2
+            | Profession::Tuk2
+            | Profession::Uai1 => 2,
+        }
+    }
+
+    /// Whether the piece can move any number of squares along at least one orthogonal direction, outside tam2 hue.
+    /// ／皇処の外で、少なくとも一つの縦横方向に何マスでも進めるか。
+    ///
+    /// The flags below follow the y1 huap1 (the standardized rule). Pieces in tam2 hue gain extra moves, which these flags do not describe.
+    /// ／以下のフラグは官定に従う。皇処にいる駒は追加の動きを得るが、それはこれらのフラグには反映されない。
+    ///
+    /// | profession | `slides_orthogonally` | `slides_diagonally` | `steps_one` | rule |
+    /// |---|---|---|---|---|
+    /// | Nuak1 船 | yes | | | forward, any distance |
+    /// | Kauk2 兵 | | | yes | forward, one square |
+    /// | Gua2 弓 | yes | | | orthogonally, any distance |
+    /// | Kaun1 車 | | yes | | diagonally, any distance |
+    /// | Dau2 虎 | | | yes | diagonally, one square |
+    /// | Maun1 馬 | | | | diagonally, jumping exactly two squares |
+    /// | Kua2 筆 | yes | | yes | vertically any distance, horizontally one square |
+    /// | Tuk2 巫 | yes | | yes | horizontally any distance, vertically one square |
+    /// | Uai1 将 | | | yes | one square, in any direction but straight back |
+    /// | Io 王 | | | yes | one square, in any of the eight directions |
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert!(Profession::Gua2.slides_orthogonally());
+    /// assert!(!Profession::Kaun1.slides_orthogonally());
+    /// ```
+    #[must_use]
+    pub const fn slides_orthogonally(self) -> bool {
+        matches!(
+            self,
+            Profession::Nuak1 | Profession::Gua2 | Profession::Kua2 | Profession::Tuk2
+        )
+    }
+
+    /// Whether the piece can move any number of squares along at least one diagonal direction, outside tam2 hue.
+    /// ／皇処の外で、少なくとも一つの斜め方向に何マスでも進めるか。
+    ///
+    /// See [`slides_orthogonally`](#method.slides_orthogonally) for the table of all flags.
+    /// ／全フラグの一覧は [`slides_orthogonally`](#method.slides_orthogonally) を参照。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert!(Profession::Kaun1.slides_diagonally());
+    /// assert!(!Profession::Maun1.slides_diagonally());
+    /// ```
+    #[must_use]
+    pub const fn slides_diagonally(self) -> bool {
+        matches!(self, Profession::Kaun1)
+    }
+
+    /// Whether the piece has a move that is limited to exactly one square, outside tam2 hue.
+    /// ／皇処の外で、ちょうど1マスだけ進む動きを持つか。
+    ///
+    /// See [`slides_orthogonally`](#method.slides_orthogonally) for the table of all flags.
+    /// ／全フラグの一覧は [`slides_orthogonally`](#method.slides_orthogonally) を参照。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert!(Profession::Io.steps_one());
+    /// assert!(Profession::Kua2.steps_one());
+    /// assert!(!Profession::Gua2.steps_one());
+    /// ```
+    #[must_use]
+    pub const fn steps_one(self) -> bool {
+        matches!(
+            self,
+            Profession::Kauk2
+                | Profession::Dau2
+                | Profession::Kua2
+                | Profession::Tuk2
+                | Profession::Uai1
+                | Profession::Io
+        )
+    }
+
+    /// Returns the movement family that the profession belongs to.
+    /// ／職種が属する動きの系統を返す。
+    ///
+    /// The assignment follows the movement table in [`slides_orthogonally`](#method.slides_orthogonally); see [`ProfessionFamily`](./enum.ProfessionFamily.html) for each family.
+    /// ／割り当ては [`slides_orthogonally`](#method.slides_orthogonally) の動きの表に従う。各系統については [`ProfessionFamily`](./enum.ProfessionFamily.html) を参照。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert_eq!(Profession::Gua2.family(), ProfessionFamily::Slider);
+    /// for prof in Profession::all().iter() {
+    ///     assert!(prof.family().members().contains(prof));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn family(self) -> ProfessionFamily {
+        match self {
+            Profession::Gua2 | Profession::Kaun1 => ProfessionFamily::Slider,
+            Profession::Nuak1 | Profession::Kua2 | Profession::Tuk2 => {
+                ProfessionFamily::PartialSlider
+            }
+            Profession::Kauk2 | Profession::Dau2 | Profession::Uai1 | Profession::Io => {
+                ProfessionFamily::Stepper
+            }
+            Profession::Maun1 => ProfessionFamily::Jumper,
+        }
+    }
+
+    /// Parses the single-letter code given by [`notation_letter`](#method.notation_letter).
+    /// ／[`notation_letter`](#method.notation_letter) の一文字表記を職種に戻す。
+    #[must_use]
+    pub const fn from_notation_letter(c: char) -> Option<Profession> {
+        match c {
+            'v' => Some(Profession::Nuak1),
+            'p' => Some(Profession::Kauk2),
+            'r' => Some(Profession::Gua2),
+            'b' => Some(Profession::Kaun1),
+            't' => Some(Profession::Dau2),
+            'h' => Some(Profession::Maun1),
+            'c' => Some(Profession::Kua2),
+            's' => Some(Profession::Tuk2),
+            'g' => Some(Profession::Uai1),
+            'k' => Some(Profession::Io),
+            _ => None,
+        }
+    }
+}
+
+use std::convert::TryFrom;
 use std::str::FromStr;
 impl FromStr for Profession {
-    type Err = ();
+    type Err = ParseProfessionError;
 
     /// Parses [`Profession`](./enum.Profession.html).
     /// ／文字列を[`Profession`](./enum.Profession.html)にする。簡体字やリパライン語名などにも対応。
@@ -107,44 +568,190 @@ impl FromStr for Profession {
     /// ```
     ///
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.to_lowercase();
-        match &*s {
-            "vessel" | "船" | "felkana" | "nuak1" | "muak1" | "pelkana" | "pijume" | "muak" => {
-                Ok(Profession::Nuak1)
-            }
-            "pawn" | "兵" | "elmer" | "kauk2" | "elme" | "kauk" => Ok(Profession::Kauk2),
-            "rook" | "弓" | "gustuer" | "gua2" | "kucte" | "kuctu" => Ok(Profession::Gua2),
-            "bishop" | "車" | "车" | "vadyrd" | "kaun1" | "badut" | "xije" | "kaun" => {
-                Ok(Profession::Kaun1)
-            }
-            "tiger" | "虎" | "stistyst" | "dau2" | "cictus" | "cucit" | "dau" => {
-                Ok(Profession::Dau2)
-            }
-            "horse" | "馬" | "马" | "dodor" | "maun1" | "dodo" | "maun" => Ok(Profession::Maun1),
-            "clerk" | "筆" | "笔" | "kua" | "kua2" | "kuwa" => Ok(Profession::Kua2),
-            "shaman" | "巫" | "terlsk" | "tuk2" | "tamcuk" | "tancuk" => Ok(Profession::Tuk2),
-            "general" | "将" | "varxle" | "uai1" | "baxule" | "xan" | "wai" => {
-                Ok(Profession::Uai1)
-            }
-            "king" | "王" | "ales" | "io" | "xet" | "caupla" => Ok(Profession::Io),
-            _ => Err(()),
+        Self::parse_verbose(s).map(|(prof, _)| prof)
+    }
+}
+
+/// The accepted spellings of each profession. The first one in each list is the canonical romanization.
+const PROFESSION_SYNONYMS: [(Profession, &[&str]); 10] = [
+    (
+        Profession::Nuak1,
+        &[
+            "nuak1", "vessel", "船", "felkana", "muak1", "pelkana", "pijume", "muak",
+        ],
+    ),
+    (
+        Profession::Kauk2,
+        &["kauk2", "pawn", "兵", "elmer", "elme", "kauk"],
+    ),
+    (
+        Profession::Gua2,
+        &["gua2", "rook", "弓", "gustuer", "kucte", "kuctu"],
+    ),
+    (
+        Profession::Kaun1,
+        &[
+            "kaun1", "bishop", "車", "车", "vadyrd", "badut", "xije", "kaun",
+        ],
+    ),
+    (
+        Profession::Dau2,
+        &["dau2", "tiger", "虎", "stistyst", "cictus", "cucit", "dau"],
+    ),
+    (
+        Profession::Maun1,
+        &["maun1", "horse", "馬", "马", "dodor", "dodo", "maun"],
+    ),
+    (
+        Profession::Kua2,
+        &["kua2", "clerk", "筆", "笔", "kua", "kuwa"],
+    ),
+    (
+        Profession::Tuk2,
+        &["tuk2", "shaman", "巫", "terlsk", "tamcuk", "tancuk"],
+    ),
+    (
+        Profession::Uai1,
+        &["uai1", "general", "将", "varxle", "baxule", "xan", "wai"],
+    ),
+    (
+        Profession::Io,
+        &["io", "king", "王", "ales", "xet", "caupla"],
+    ),
+];
+
+/// Looks up the profession case-insensitively, returning it along with its canonical romanization.
+fn lookup_profession(s: &str) -> Option<(Profession, &'static str)> {
+    let s = s.to_lowercase();
+    PROFESSION_SYNONYMS
+        .iter()
+        .find(|(_, synonyms)| synonyms.contains(&&*s))
+        .map(|&(prof, synonyms)| (prof, synonyms[0]))
+}
+
+/// Computes the Levenshtein distance between two strings, counted in `char`s.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            cur.push(substitution.min(prev[j + 1] + 1).min(cur[j] + 1));
         }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Describes a string that could not be parsed as a `Profession`, along with the accepted spellings close to it.
+/// ／`Profession` として読めなかった文字列と、それに近い受理される綴りを表す。
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseProfessionError {
+    /// the string that was given／与えられた文字列
+    pub input: String,
+
+    /// the accepted spellings within an edit distance of 2, closest first／編集距離2以内の受理される綴り（近い順）
+    pub near_misses: Vec<&'static str>,
+}
+
+impl std::fmt::Display for ParseProfessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid profession: `{}`", self.input)?;
+        if !self.near_misses.is_empty() {
+            write!(f, "; did you mean `{}`?", self.near_misses.join("`, `"))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseProfessionError {}
+
+impl Profession {
+    /// Parses the profession like `from_str`, but also reports which spelling matched or, on failure, which spellings were close.
+    /// ／`from_str` と同様に職種を読むが、どの綴りに一致したか、失敗時にはどの綴りが近かったかも報告する。
+    ///
+    /// On success, the canonical romanization of the profession (such as `"nuak1"`) is returned along with it,
+    /// which tells which family of synonyms the input belongs to.
+    /// On failure, the error lists the accepted spellings within an edit distance of 2, which helps auditing localization tables.
+    /// ／成功時には職種とともにその正式なローマ字表記（`"nuak1"` など）を返す。これにより入力がどの同義語群に属するかが分かる。
+    /// 失敗時には、編集距離2以内の受理される綴りをエラーに列挙する。ローカライズ用の表の検証に役立つ。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    ///
+    /// assert_eq!(Profession::parse_verbose("Felkana"), Ok((Profession::Nuak1, "nuak1")));
+    /// assert_eq!(Profession::parse_verbose("马"), Ok((Profession::Maun1, "maun1")));
+    ///
+    /// let err = Profession::parse_verbose("bishp").unwrap_err();
+    /// assert_eq!(err.near_misses, vec!["bishop"]);
+    /// assert_eq!(err.to_string(), "invalid profession: `bishp`; did you mean `bishop`?");
+    ///
+    /// assert!(Profession::parse_verbose("qqqqqqqq").unwrap_err().near_misses.is_empty());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the string is not an accepted spelling of any profession.
+    /// ／文字列がどの職種の綴りとしても受理されない場合、エラーを返す。
+    pub fn parse_verbose(s: &str) -> Result<(Profession, &'static str), ParseProfessionError> {
+        lookup_profession(s).ok_or_else(|| {
+            let lower = s.to_lowercase();
+            let mut near: Vec<(usize, &'static str)> = PROFESSION_SYNONYMS
+                .iter()
+                .flat_map(|&(_, synonyms)| synonyms.iter().copied())
+                .map(|synonym| (edit_distance(&lower, synonym), synonym))
+                .filter(|&(d, _)| d <= 2)
+                .collect();
+            near.sort_by_key(|&(d, _)| d);
+            ParseProfessionError {
+                input: s.to_string(),
+                near_misses: near.into_iter().map(|(_, synonym)| synonym).collect(),
+            }
+        })
     }
 }
 
 impl FromStr for Color {
-    type Err = ();
+    type Err = ParseColorError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.to_lowercase();
-        match &*s {
+        match &*s.to_lowercase() {
             "red" | "赤" | "kok1" | "红" | "紅" => Ok(Color::Kok1),
             "black" | "黒" | "huok2" | "黑" => Ok(Color::Huok2),
-            _ => Err(()),
+            _ => Err(ParseColorError(s.to_string())),
         }
     }
 }
 
+/// Describes an error that occurred while parsing a [`Color`](./enum.Color.html). Contains the input that failed to parse.
+/// ／[`Color`](./enum.Color.html) のパースに失敗したことを表す。失敗した入力を保持する。
+///
+/// Like every error type of this crate, it implements `std::error::Error`, so it composes with `?` and `Box<dyn Error>`.
+/// ／このクレートの他のエラー型と同様に `std::error::Error` を実装しているので、`?` や `Box<dyn Error>` と組み合わせられる。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use std::error::Error;
+///
+/// fn parse(color: &str, prof: &str, coord: &str) -> Result<(Color, Profession, absolute::Coord), Box<dyn Error>> {
+///     Ok((color.parse()?, prof.parse()?, coord.parse()?))
+/// }
+///
+/// assert!(parse("red", "king", "ZIA").is_ok());
+/// assert_eq!(parse("blue", "king", "ZIA").unwrap_err().to_string(), "invalid color: `blue`");
+/// assert_eq!(parse("red", "king", "ZZ").unwrap_err().to_string(), "invalid coordinate: `ZZ`");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseColorError(pub String);
+
+impl std::fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid color: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
 /// Defines things in terms of relative view: "which piece is opponent's?"／相対座標ベース。「どの駒が相手の駒？」という話をする
 pub mod relative;
 
@@ -159,7 +766,11 @@ impl serde::ser::Serialize for Color {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(serialize_color(*self))
+        if serializer.is_human_readable() {
+            serializer.serialize_str(serialize_color(*self))
+        } else {
+            serializer.serialize_u8(self.to_u8())
+        }
     }
 }
 
@@ -168,13 +779,17 @@ impl serde::ser::Serialize for Profession {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(serialize_prof(*self))
+        if serializer.is_human_readable() {
+            serializer.serialize_str(serialize_prof(*self))
+        } else {
+            serializer.serialize_u8(self.to_u8())
+        }
     }
 }
 
 struct ColorVisitor;
 
-impl<'de> serde::de::Visitor<'de> for ColorVisitor {
+impl serde::de::Visitor<'_> for ColorVisitor {
     type Value = Color;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -185,13 +800,20 @@ impl<'de> serde::de::Visitor<'de> for ColorVisitor {
     where
         E: serde::de::Error,
     {
-        match Color::from_str(s) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(serde::de::Error::invalid_value(
-                serde::de::Unexpected::Str(s),
-                &self,
-            )),
-        }
+        Color::from_str(s)
+            .map_err(|_| serde::de::Error::invalid_value(serde::de::Unexpected::Str(s), &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        u8::try_from(v)
+            .ok()
+            .and_then(Color::from_u8)
+            .ok_or_else(|| {
+                serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+            })
     }
 }
 
@@ -200,13 +822,17 @@ impl<'de> serde::de::Deserialize<'de> for Color {
     where
         D: serde::de::Deserializer<'de>,
     {
-        deserializer.deserialize_str(ColorVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColorVisitor)
+        } else {
+            deserializer.deserialize_u8(ColorVisitor)
+        }
     }
 }
 
 struct ProfessionVisitor;
 
-impl<'de> serde::de::Visitor<'de> for ProfessionVisitor {
+impl serde::de::Visitor<'_> for ProfessionVisitor {
     type Value = Profession;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -217,13 +843,20 @@ impl<'de> serde::de::Visitor<'de> for ProfessionVisitor {
     where
         E: serde::de::Error,
     {
-        match Profession::from_str(s) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(serde::de::Error::invalid_value(
-                serde::de::Unexpected::Str(s),
-                &self,
-            )),
-        }
+        Profession::from_str(s)
+            .map_err(|_| serde::de::Error::invalid_value(serde::de::Unexpected::Str(s), &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        u8::try_from(v)
+            .ok()
+            .and_then(Profession::from_u8)
+            .ok_or_else(|| {
+                serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+            })
     }
 }
 
@@ -232,7 +865,11 @@ impl<'de> serde::de::Deserialize<'de> for Profession {
     where
         D: serde::de::Deserializer<'de>,
     {
-        deserializer.deserialize_str(ProfessionVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ProfessionVisitor)
+        } else {
+            deserializer.deserialize_u8(ProfessionVisitor)
+        }
     }
 }
 
@@ -249,7 +886,7 @@ macro_rules! cp {
 }
 
 /// A shortcut macro for creating `Profession`.
-/// ／`Profession` を楽に構築するためのマクロ。 
+/// ／`Profession` を楽に構築するためのマクロ。
 #[macro_export]
 macro_rules! prof {
     ('船') => {
@@ -293,7 +930,6 @@ macro_rules! prof {
     };
 }
 
-
 /// A shortcut macro for creating `Color`.
 /// ／`Color` を楽に構築するためのマクロ。
 #[macro_export]
@@ -5,15 +5,15 @@ use serde::{Deserialize, Serialize};
 /// 視点を固定すると、相対座標表現と絶対座標表現を相互変換することができる。
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub enum Perspective {
-    /// IA is the lowermost row; 
+    /// IA is the lowermost row;
     /// the player who had occupied the IA row in the beginning of the game has pieces that point upward
-    /// (i.e. you) 
+    /// (i.e. you)
     /// ／IAは一番下の行であり、初期状態でIA行を占有していたプレイヤーは駒が上向き（=あなた）である。
     IaIsDownAndPointsUpward,
 
-    /// IA is the uppermost row; 
+    /// IA is the uppermost row;
     /// the player who had occupied the IA row in the beginning of the game has pieces that point downward
-    /// (i.e. the opponent) 
+    /// (i.e. the opponent)
     /// ／IAは一番上の行であり、初期状態でIA行を占有していたプレイヤーは駒が下向き（=相手）である。
     IaIsUpAndPointsDownward,
 }
@@ -25,6 +25,53 @@ impl Perspective {
     pub const fn ia_is_down(self) -> bool {
         matches!(self, Perspective::IaIsDownAndPointsUpward)
     }
+
+    /// Converts `relative::Side` into `absolute::Side` as seen from this perspective. Same as [`to_absolute_side`](./fn.to_absolute_side.html).
+    /// ／この視点において `relative::Side` を `absolute::Side` に変換する。[`to_absolute_side`](./fn.to_absolute_side.html) と同じ。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::perspective::*;
+    ///
+    /// let p = Perspective::IaIsDownAndPointsUpward;
+    /// assert_eq!(p.absolute_side(relative::Side::Upward), absolute::Side::IASide);
+    /// assert_eq!(p.relative_side(absolute::Side::IASide), relative::Side::Upward);
+    /// ```
+    #[must_use]
+    pub const fn absolute_side(self, side: relative::Side) -> absolute::Side {
+        to_absolute_side(side, self)
+    }
+
+    /// Converts `absolute::Side` into `relative::Side` as seen from this perspective. Same as [`to_relative_side`](./fn.to_relative_side.html).
+    /// ／この視点において `absolute::Side` を `relative::Side` に変換する。[`to_relative_side`](./fn.to_relative_side.html) と同じ。
+    #[must_use]
+    pub const fn relative_side(self, side: absolute::Side) -> relative::Side {
+        to_relative_side(side, self)
+    }
+
+    /// Converts `relative::Coord` into `absolute::Coord` as seen from this perspective. Same as [`to_absolute_coord`](./fn.to_absolute_coord.html).
+    /// ／この視点において `relative::Coord` を `absolute::Coord` に変換する。[`to_absolute_coord`](./fn.to_absolute_coord.html) と同じ。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::perspective::*;
+    ///
+    /// let p = Perspective::IaIsUpAndPointsDownward;
+    /// let coord = absolute::Coord(absolute::Row::I, absolute::Column::Z);
+    /// assert_eq!(p.to_relative(coord), [6, 4]);
+    /// assert_eq!(p.to_absolute(p.to_relative(coord)), coord);
+    /// ```
+    #[must_use]
+    pub fn to_absolute(self, coord: relative::Coord) -> absolute::Coord {
+        to_absolute_coord(coord, self)
+    }
+
+    /// Converts `absolute::Coord` into `relative::Coord` as seen from this perspective. Same as [`to_relative_coord`](./fn.to_relative_coord.html).
+    /// ／この視点において `absolute::Coord` を `relative::Coord` に変換する。[`to_relative_coord`](./fn.to_relative_coord.html) と同じ。
+    #[must_use]
+    pub const fn to_relative(self, coord: absolute::Coord) -> relative::Coord {
+        to_relative_coord(coord, self)
+    }
 }
 
 /// Converts `relative::Board` into `absolute::Board`.
@@ -61,13 +108,86 @@ pub fn to_relative_board(board: &absolute::Board, p: Perspective) -> relative::B
     for (i, row) in ans.iter_mut().enumerate() {
         for (j, sq) in row.iter_mut().enumerate() {
             if let Some(piece) = board.get(&to_absolute_coord([i, j], p)) {
-                *sq = Some(to_relative_piece(*piece, p))
+                *sq = Some(to_relative_piece(*piece, p));
             }
         }
     }
     ans
 }
 
+/// Converts the two relative hop1zuo1s into the absolute ones, without touching the board.
+/// ／盤には触れず、相対表現の二つの手駒を絶対表現に変換する。
+///
+/// Returns the hop1zuo1 of `ASide` and that of `IASide`, in this order.
+/// ／`ASide` の手駒と `IASide` の手駒をこの順に返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::perspective::*;
+///
+/// let upward = vec![relative::NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Io }];
+/// let (a_side, ia_side) = hop1zuo1_to_absolute(&upward, &[], Perspective::IaIsDownAndPointsUpward);
+/// assert!(a_side.is_empty());
+/// assert_eq!(ia_side, vec![absolute::NonTam2Piece { color: Color::Kok1, prof: Profession::Io }]);
+/// assert_eq!(
+///     hop1zuo1_to_relative(&a_side, &ia_side, Perspective::IaIsDownAndPointsUpward),
+///     (upward, vec![])
+/// );
+/// ```
+#[must_use]
+pub fn hop1zuo1_to_absolute(
+    upward: &[relative::NonTam2PieceUpward],
+    downward: &[relative::NonTam2PieceDownward],
+    p: Perspective,
+) -> (Vec<absolute::NonTam2Piece>, Vec<absolute::NonTam2Piece>) {
+    let upward = upward
+        .iter()
+        .copied()
+        .map(absolute::NonTam2Piece::from)
+        .collect();
+    let downward = downward
+        .iter()
+        .copied()
+        .map(absolute::NonTam2Piece::from)
+        .collect();
+    match p {
+        Perspective::IaIsDownAndPointsUpward => (downward, upward),
+        Perspective::IaIsUpAndPointsDownward => (upward, downward),
+    }
+}
+
+/// Converts the two absolute hop1zuo1s into the relative ones, without touching the board.
+/// ／盤には触れず、絶対表現の二つの手駒を相対表現に変換する。
+///
+/// Returns the hop1zuo1 of the upward side and that of the downward side, in this order.
+/// ／上向きの陣営の手駒と下向きの陣営の手駒をこの順に返す。
+#[must_use]
+pub fn hop1zuo1_to_relative(
+    a_side: &[absolute::NonTam2Piece],
+    ia_side: &[absolute::NonTam2Piece],
+    p: Perspective,
+) -> (
+    Vec<relative::NonTam2PieceUpward>,
+    Vec<relative::NonTam2PieceDownward>,
+) {
+    let (upward, downward) = match p {
+        Perspective::IaIsDownAndPointsUpward => (ia_side, a_side),
+        Perspective::IaIsUpAndPointsDownward => (a_side, ia_side),
+    };
+    (
+        upward
+            .iter()
+            .copied()
+            .map(relative::NonTam2PieceUpward::from)
+            .collect(),
+        downward
+            .iter()
+            .copied()
+            .map(relative::NonTam2PieceDownward::from)
+            .collect(),
+    )
+}
+
 /// Converts `relative::Field` into `absolute::Field`.
 /// ／`relative::Field` を `absolute::Field` に変換する。
 #[must_use]
@@ -77,52 +197,12 @@ pub fn to_absolute_field(field: relative::Field, p: Perspective) -> absolute::Fi
         hop1zuo1of_upward,
         current_board,
     } = field;
+    let (a_side_hop1zuo1, ia_side_hop1zuo1) =
+        hop1zuo1_to_absolute(&hop1zuo1of_upward, &hop1zuo1of_downward, p);
     absolute::Field {
         board: to_absolute_board(&current_board, p),
-        ia_side_hop1zuo1: match p {
-            Perspective::IaIsDownAndPointsUpward => hop1zuo1of_upward
-                .iter()
-                .copied()
-                .map(
-                    |relative::NonTam2PieceUpward { color, prof }| absolute::NonTam2Piece {
-                        color,
-                        prof,
-                    },
-                )
-                .collect(),
-            Perspective::IaIsUpAndPointsDownward => hop1zuo1of_downward
-                .iter()
-                .copied()
-                .map(
-                    |relative::NonTam2PieceDownward { color, prof }| absolute::NonTam2Piece {
-                        color,
-                        prof,
-                    },
-                )
-                .collect(),
-        },
-        a_side_hop1zuo1: match p {
-            Perspective::IaIsDownAndPointsUpward => hop1zuo1of_downward
-                .iter()
-                .copied()
-                .map(
-                    |relative::NonTam2PieceDownward { color, prof }| absolute::NonTam2Piece {
-                        color,
-                        prof,
-                    },
-                )
-                .collect(),
-            Perspective::IaIsUpAndPointsDownward => hop1zuo1of_upward
-                .iter()
-                .copied()
-                .map(
-                    |relative::NonTam2PieceUpward { color, prof }| absolute::NonTam2Piece {
-                        color,
-                        prof,
-                    },
-                )
-                .collect(),
-        },
+        ia_side_hop1zuo1,
+        a_side_hop1zuo1,
     }
 }
 
@@ -135,22 +215,11 @@ pub fn to_relative_field(field: absolute::Field, p: Perspective) -> relative::Fi
         ia_side_hop1zuo1,
         a_side_hop1zuo1,
     } = field;
-
+    let (hop1zuo1of_upward, hop1zuo1of_downward) =
+        hop1zuo1_to_relative(&a_side_hop1zuo1, &ia_side_hop1zuo1, p);
     relative::Field {
-        hop1zuo1of_downward: match p {
-            Perspective::IaIsUpAndPointsDownward => ia_side_hop1zuo1.iter().copied(),
-            Perspective::IaIsDownAndPointsUpward => a_side_hop1zuo1.iter().copied(),
-        }
-        .map(
-            |absolute::NonTam2Piece { color, prof }| relative::NonTam2PieceDownward { color, prof },
-        )
-        .collect(),
-        hop1zuo1of_upward: match p {
-            Perspective::IaIsUpAndPointsDownward => a_side_hop1zuo1.iter().copied(),
-            Perspective::IaIsDownAndPointsUpward => ia_side_hop1zuo1.iter().copied(),
-        }
-        .map(|absolute::NonTam2Piece { color, prof }| relative::NonTam2PieceUpward { color, prof })
-        .collect(),
+        hop1zuo1of_downward,
+        hop1zuo1of_upward,
         current_board: to_relative_board(&board, p),
     }
 }
@@ -341,3 +410,181 @@ pub const fn to_relative_coord(coord: absolute::Coord, p: Perspective) -> relati
         [8 - rows_row, 8 - columns_col]
     }
 }
+
+/// Converts a slice of `relative::Coord` into `absolute::Coord`s.
+/// ／`relative::Coord` の列をまとめて `absolute::Coord` に変換する。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::perspective::*;
+/// assert_eq!(
+///     to_absolute_coords(&[[2, 4], [0, 0]], Perspective::IaIsUpAndPointsDownward),
+///     vec![
+///         absolute::Coord(absolute::Row::AI, absolute::Column::Z),
+///         absolute::Coord(absolute::Row::IA, absolute::Column::P)
+///     ]
+/// )
+/// ```
+#[must_use]
+pub fn to_absolute_coords(coords: &[relative::Coord], p: Perspective) -> Vec<absolute::Coord> {
+    use absolute::{COLUMNS, ROWS};
+    if p.ia_is_down() {
+        coords
+            .iter()
+            .map(|&[row, col]| absolute::Coord(ROWS[row], COLUMNS[col]))
+            .collect()
+    } else {
+        coords
+            .iter()
+            .map(|&[row, col]| absolute::Coord(ROWS[8 - row], COLUMNS[8 - col]))
+            .collect()
+    }
+}
+
+/// Converts a slice of `absolute::Coord` into `relative::Coord`s.
+/// ／`absolute::Coord` の列をまとめて `relative::Coord` に変換する。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::perspective::*;
+/// assert_eq!(
+///     to_relative_coords(
+///         &[absolute::Coord(absolute::Row::I, absolute::Column::Z)],
+///         Perspective::IaIsDownAndPointsUpward
+///     ),
+///     vec![[2, 4]]
+/// )
+/// ```
+#[must_use]
+pub fn to_relative_coords(coords: &[absolute::Coord], p: Perspective) -> Vec<relative::Coord> {
+    use absolute::{column_index, row_index};
+    if p.ia_is_down() {
+        coords
+            .iter()
+            .map(|&absolute::Coord(row, col)| [row_index(row), column_index(col)])
+            .collect()
+    } else {
+        coords
+            .iter()
+            .map(|&absolute::Coord(row, col)| [8 - row_index(row), 8 - column_index(col)])
+            .collect()
+    }
+}
+
+/// Checks whether `absolute::Piece` and `relative::Piece` denote the same piece under the perspective.
+/// ／視点 `p` のもとで、`absolute::Piece` と `relative::Piece` が同じ駒を表しているかどうかを調べる。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::perspective::*;
+/// let abs = absolute::Piece::NonTam2Piece {
+///     prof: Profession::Uai1,
+///     color: Color::Kok1,
+///     side: absolute::Side::IASide
+/// };
+/// let rel = relative::Piece::NonTam2Piece {
+///     prof: Profession::Uai1,
+///     color: Color::Kok1,
+///     side: relative::Side::Upward
+/// };
+/// assert!(pieces_equal(abs, rel, Perspective::IaIsDownAndPointsUpward));
+/// assert!(!pieces_equal(abs, rel, Perspective::IaIsUpAndPointsDownward));
+/// assert!(pieces_equal(absolute::Piece::Tam2, relative::Piece::Tam2, Perspective::IaIsUpAndPointsDownward));
+/// ```
+#[must_use]
+pub fn pieces_equal(abs: absolute::Piece, rel: relative::Piece, p: Perspective) -> bool {
+    match (abs, rel) {
+        (absolute::Piece::Tam2, relative::Piece::Tam2) => true,
+        (
+            absolute::Piece::NonTam2Piece {
+                color: abs_color,
+                prof: abs_prof,
+                side: abs_side,
+            },
+            relative::Piece::NonTam2Piece {
+                color: rel_color,
+                prof: rel_prof,
+                side: rel_side,
+            },
+        ) => {
+            abs_color == rel_color
+                && abs_prof == rel_prof
+                && to_relative_side(abs_side, p) == rel_side
+        }
+        _ => false,
+    }
+}
+
+/// Converts a single-square update, i.e. a coordinate and what is now there, into the relative one.
+/// ／一マス分の更新（座標と、そこに今あるもの）を相対表現に変換する。
+///
+/// This is the one-square version of [`to_relative_board`](./fn.to_relative_board.html),
+/// for applying a change without converting the whole board.
+/// ／[`to_relative_board`](./fn.to_relative_board.html) の一マス版であり、盤全体を変換せずに変更を適用するためのものである。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::perspective::*;
+///
+/// let coord = absolute::Coord(absolute::Row::A, absolute::Column::K);
+/// assert_eq!(
+///     mirror_update(coord, Some(absolute::Piece::Tam2), Perspective::IaIsDownAndPointsUpward),
+///     ([0, 0], Some(relative::Piece::Tam2))
+/// );
+/// assert_eq!(mirror_update(coord, None, Perspective::IaIsUpAndPointsDownward), ([8, 8], None));
+/// ```
+#[must_use]
+pub fn mirror_update(
+    coord: absolute::Coord,
+    piece: Option<absolute::Piece>,
+    p: Perspective,
+) -> (relative::Coord, Option<relative::Piece>) {
+    (
+        to_relative_coord(coord, p),
+        piece.map(|piece| to_relative_piece(piece, p)),
+    )
+}
+
+/// Displays an `absolute::Board` as seen from the given perspective, one row per line.
+/// ／`absolute::Board` を、指定した視点から見たものとして一行ずつ表示する。
+///
+/// Each square is shown as in [`relative::serialize_piece`](../relative/fn.serialize_piece.html), and an empty square as `・`;
+/// squares are separated by a space. With `IaIsUpAndPointsDownward`, the rows and the columns are thus both reversed.
+/// ／各マスは [`relative::serialize_piece`](../relative/fn.serialize_piece.html) と同じ形式で、空きマスは `・` で表示し、
+/// マスの間は空白で区切る。したがって `IaIsUpAndPointsDownward` では行も列も逆順になる。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::perspective::*;
+///
+/// let board = maplit::hashmap! {
+///     absolute::Coord(absolute::Row::IA, absolute::Column::K) => absolute::Piece::NonTam2Piece {
+///         color: Color::Kok1,
+///         prof: Profession::Io,
+///         side: absolute::Side::IASide,
+///     },
+/// };
+///
+/// let seen_from_ia = DisplayBoard(&board, Perspective::IaIsDownAndPointsUpward).to_string();
+/// assert_eq!(seen_from_ia.lines().count(), 9);
+/// assert_eq!(seen_from_ia.lines().last(), Some("赤王↑ ・ ・ ・ ・ ・ ・ ・ ・"));
+///
+/// let seen_from_a = DisplayBoard(&board, Perspective::IaIsUpAndPointsDownward).to_string();
+/// assert_eq!(seen_from_a.lines().next(), Some("・ ・ ・ ・ ・ ・ ・ ・ 赤王↓"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayBoard<'a>(pub &'a absolute::Board, pub Perspective);
+
+impl std::fmt::Display for DisplayBoard<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let DisplayBoard(board, p) = *self;
+        for row in &to_relative_board(board, p) {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|sq| sq.map_or_else(|| "・".to_string(), relative::serialize_piece))
+                .collect();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+        Ok(())
+    }
+}
@@ -24,6 +24,21 @@ impl std::ops::Not for Side {
     }
 }
 
+impl Side {
+    /// Returns both sides, `Upward` first.
+    /// ／両陣営を `Upward` から順に返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::relative::*;
+    ///
+    /// assert_eq!(Side::all(), [Side::Upward, Side::Downward]);
+    /// ```
+    #[must_use]
+    pub const fn all() -> [Side; 2] {
+        [Side::Upward, Side::Downward]
+    }
+}
+
 /// Describes a piece that is not a Tam2 and points downward (i.e. opponents).
 /// ／駒のうち、皇ではなくて、下向き（つまり相手陣営）のものを表す。
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -64,6 +79,66 @@ impl From<NonTam2PieceDownward> for Piece {
     }
 }
 
+/// Forgets the direction, since `absolute::NonTam2Piece` only records color and profession.
+/// ／向きの情報を捨てる。`absolute::NonTam2Piece` は色と職種しか持たないため。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+///
+/// let piece = relative::NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Io };
+/// assert_eq!(
+///     absolute::NonTam2Piece::from(piece),
+///     absolute::NonTam2Piece { color: Color::Kok1, prof: Profession::Io }
+/// );
+/// ```
+impl From<NonTam2PieceUpward> for super::absolute::NonTam2Piece {
+    fn from(from: NonTam2PieceUpward) -> super::absolute::NonTam2Piece {
+        super::absolute::NonTam2Piece {
+            color: from.color,
+            prof: from.prof,
+        }
+    }
+}
+
+impl From<NonTam2PieceDownward> for super::absolute::NonTam2Piece {
+    fn from(from: NonTam2PieceDownward) -> super::absolute::NonTam2Piece {
+        super::absolute::NonTam2Piece {
+            color: from.color,
+            prof: from.prof,
+        }
+    }
+}
+
+/// Gives the piece a direction, which is specified by choosing `NonTam2PieceUpward` or `NonTam2PieceDownward` as the target.
+/// ／駒に向きを与える。`NonTam2PieceUpward` と `NonTam2PieceDownward` のどちらに変換するかで向きを指定する。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+///
+/// let piece = absolute::NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2 };
+/// assert_eq!(
+///     relative::NonTam2PieceDownward::from(piece),
+///     relative::NonTam2PieceDownward { color: Color::Huok2, prof: Profession::Kauk2 }
+/// );
+/// ```
+impl From<super::absolute::NonTam2Piece> for NonTam2PieceUpward {
+    fn from(from: super::absolute::NonTam2Piece) -> NonTam2PieceUpward {
+        NonTam2PieceUpward {
+            color: from.color,
+            prof: from.prof,
+        }
+    }
+}
+
+impl From<super::absolute::NonTam2Piece> for NonTam2PieceDownward {
+    fn from(from: super::absolute::NonTam2Piece) -> NonTam2PieceDownward {
+        NonTam2PieceDownward {
+            color: from.color,
+            prof: from.prof,
+        }
+    }
+}
+
 /// Describes a piece on the board.
 /// ／盤上に存在できる駒を表現する。
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -128,22 +203,39 @@ impl Piece {
     }
 }
 
+/// Flips the side of a piece, as happens when the board is rotated. Tam2 is left unchanged.
+/// ／駒の陣営を入れ替える。盤を回転させたときに起こることである。皇はそのまま。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::relative::*;
+///
+/// assert_eq!(rotate_piece(Piece::Tam2), Piece::Tam2);
+/// assert_eq!(
+///     rotate_piece(Piece::NonTam2Piece { prof: Profession::Io, color: Color::Kok1, side: Side::Upward }),
+///     Piece::NonTam2Piece { prof: Profession::Io, color: Color::Kok1, side: Side::Downward }
+/// );
+/// ```
 #[must_use]
-fn rotate_piece_or_null(p: Option<Piece>) -> Option<Piece> {
-    let p = p?;
+pub fn rotate_piece(p: Piece) -> Piece {
     match p {
-        Piece::Tam2 => Some(p),
-        Piece::NonTam2Piece { prof, color, side } => Some(Piece::NonTam2Piece {
+        Piece::Tam2 => p,
+        Piece::NonTam2Piece { prof, color, side } => Piece::NonTam2Piece {
             prof,
             color,
             side: !side,
-        }),
+        },
     }
 }
 
+#[must_use]
+fn rotate_piece_or_null(p: Option<Piece>) -> Option<Piece> {
+    p.map(rotate_piece)
+}
+
 /// Denotes the position of a square by [row, col].
 /// ／マス目の相対座標を [row, col] で表す。
-/// 
+///
 pub type Coord = [usize; 2];
 
 /// Serializes [`Coord`](./type.Coord.html) in JSON-style.
@@ -160,6 +252,24 @@ pub fn serialize_coord(coord: Coord) -> String {
     format!("[{},{}]", coord[0], coord[1])
 }
 
+/// Checks whether both components of the coordinate are in `0..=8`, i.e. whether it is on the board.
+/// ／座標の両成分が `0..=8` の範囲にある、つまり盤上にあるかどうかを調べる。
+///
+/// Functions such as [`is_water`](./fn.is_water.html) and [`distance`](./fn.distance.html) assume a valid coordinate; use this to check untrusted input first.
+/// ／[`is_water`](./fn.is_water.html) や [`distance`](./fn.distance.html) などは正しい座標を前提とするので、信頼できない入力はまずこれで確認すること。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// assert!(is_valid_coord([8, 0]));
+/// assert!(!is_valid_coord([9, 0]));
+/// assert!(!is_valid_coord([0, usize::MAX]));
+/// ```
+#[must_use]
+pub const fn is_valid_coord([row, col]: Coord) -> bool {
+    row <= 8 && col <= 8
+}
+
 /// Rotates the coordinate with the center of the board as the center of rotation.
 /// ／盤の中心を基準に、座標を180度回転させる。
 #[must_use]
@@ -171,24 +281,73 @@ pub const fn rotate_coord(c: Coord) -> Coord {
 /// ／マスが皇水（たむぬあ）であるかどうかの判定
 #[must_use]
 pub const fn is_water([row, col]: Coord) -> bool {
-    (row == 4 && col == 2)
-        || (row == 4 && col == 3)
-        || (row == 4 && col == 4)
-        || (row == 4 && col == 5)
-        || (row == 4 && col == 6)
-        || (row == 2 && col == 4)
-        || (row == 3 && col == 4)
-        || (row == 5 && col == 4)
-        || (row == 6 && col == 4)
-}
-
-const fn serialize_side(side: Side) -> &'static str {
+    (row == 4 && 2 <= col && col <= 6) || (col == 4 && 2 <= row && row <= 6)
+}
+
+/// Lists the nine squares that are tam2 nua2 (tam2's water).
+/// ／皇水（たむぬあ）である9マスを列挙する。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// assert!(water_coords().iter().all(|&c| is_water(c)));
+///
+/// let count = (0..9)
+///     .flat_map(|row| (0..9).map(move |col| [row, col]))
+///     .filter(|&c| is_water(c))
+///     .count();
+/// assert_eq!(count, 9);
+/// ```
+#[must_use]
+pub const fn water_coords() -> [Coord; 9] {
+    [
+        [2, 4],
+        [3, 4],
+        [4, 2],
+        [4, 3],
+        [4, 4],
+        [4, 5],
+        [4, 6],
+        [5, 4],
+        [6, 4],
+    ]
+}
+
+/// Serializes [`Side`](./enum.Side.html) as an arrow: `"↑"` for `Upward` and `"↓"` for `Downward`.
+/// ／[`Side`](./enum.Side.html) を矢印にする。`Upward` は `"↑"`、`Downward` は `"↓"` である。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// assert_eq!(serialize_side(Side::Upward), "↑");
+/// assert_eq!(parse_side(serialize_side(Side::Downward)), Some(Side::Downward));
+/// ```
+#[must_use]
+pub const fn serialize_side(side: Side) -> &'static str {
     match side {
         Side::Upward => "↑",
         Side::Downward => "↓",
     }
 }
 
+/// Parses the arrow given by [`serialize_side`](./fn.serialize_side.html).
+/// ／[`serialize_side`](./fn.serialize_side.html) の矢印を [`Side`](./enum.Side.html) に戻す。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// assert_eq!(parse_side("↑"), Some(Side::Upward));
+/// assert_eq!(parse_side("↑↑"), None);
+/// ```
+#[must_use]
+pub fn parse_side(s: &str) -> Option<Side> {
+    match s {
+        "↑" => Some(Side::Upward),
+        "↓" => Some(Side::Downward),
+        _ => None,
+    }
+}
+
 /// Serializes [`Piece`](./enum.Piece.html).
 /// ／[`Piece`](./enum.Piece.html) を文字列にする。
 /// # Examples
@@ -202,6 +361,9 @@ const fn serialize_side(side: Side) -> &'static str {
 ///     color: Color::Kok1,
 ///     side: Side::Downward
 /// }), "赤将↓");
+///
+/// // `Piece` also implements `Display` in the same manner
+/// assert_eq!(Piece::Tam2.to_string(), "皇");
 /// ```
 #[must_use]
 pub fn serialize_piece(p: Piece) -> String {
@@ -216,6 +378,129 @@ pub fn serialize_piece(p: Piece) -> String {
     }
 }
 
+impl std::fmt::Display for Piece {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", serialize_piece(*self))
+    }
+}
+
+/// Parses the string produced by [`serialize_piece`](./fn.serialize_piece.html).
+/// ／[`serialize_piece`](./fn.serialize_piece.html) の出力を [`Piece`](./enum.Piece.html) に戻す。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::relative::*;
+///
+/// assert_eq!(parse_piece("皇"), Some(Piece::Tam2));
+/// assert_eq!(
+///     parse_piece("赤将↓"),
+///     Some(Piece::NonTam2Piece { prof: Profession::Uai1, color: Color::Kok1, side: Side::Downward })
+/// );
+/// assert_eq!(parse_piece("赤将"), None);
+/// assert_eq!(parse_piece("赤将↓↓"), None);
+/// ```
+#[must_use]
+pub fn parse_piece(s: &str) -> Option<Piece> {
+    use std::str::FromStr;
+    if s == "皇" {
+        return Some(Piece::Tam2);
+    }
+    let mut chars = s.chars();
+    let color = Color::from_str(&chars.next()?.to_string()).ok()?;
+    let prof = Profession::from_str(&chars.next()?.to_string()).ok()?;
+    let side = parse_side(chars.as_str())?;
+    Some(Piece::NonTam2Piece { color, prof, side })
+}
+
+impl serde::ser::Serialize for Piece {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&serialize_piece(*self))
+    }
+}
+
+struct PieceVisitor;
+
+impl serde::de::Visitor<'_> for PieceVisitor {
+    type Value = Piece;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a piece")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        parse_piece(s)
+            .ok_or_else(|| serde::de::Error::invalid_value(serde::de::Unexpected::Str(s), &self))
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for Piece {
+    fn deserialize<D>(deserializer: D) -> Result<Piece, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PieceVisitor)
+    }
+}
+
+/// Serializes `Board` as a 9x9 nested array of pieces.
+/// ／`Board` を駒の 9x9 の入れ子の配列としてシリアライズする。
+///
+/// Each piece is a string given by [`serialize_piece`](./fn.serialize_piece.html); an empty square is serialized as `null` in JSON.
+/// ／各駒は [`serialize_piece`](./fn.serialize_piece.html) による文字列であり、空のマスは JSON では `null` となる。
+///
+/// Since `Board` is a type alias, it cannot implement `Serialize` by itself;
+/// use this with `#[serde(serialize_with = "cetkaik_core::relative::serialize_board")]`.
+/// ／`Board` は型エイリアスなので `Serialize` を実装できない。
+/// `#[serde(serialize_with = "cetkaik_core::relative::serialize_board")]` として使う。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// let mut json = vec![];
+/// serialize_board(
+///     &yhuap_initial_board_where_black_king_points_upward(),
+///     &mut serde_json::Serializer::new(&mut json)
+/// ).unwrap();
+/// let json = String::from_utf8(json).unwrap();
+/// assert!(json.starts_with(r#"[["黒筆↓","黒馬↓","黒車↓","#));
+/// assert!(json.contains(r#"[null,null,null,null,"皇",null,null,null,null]"#));
+///
+/// let board = parse_board(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+/// assert_eq!(board, yhuap_initial_board_where_black_king_points_upward());
+/// ```
+///
+/// # Errors
+/// Returns an error if the serializer does.
+/// ／シリアライザがエラーを返したときはエラーを返す。
+pub fn serialize_board<S>(board: &Board, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::ser::Serializer,
+{
+    serde::ser::Serialize::serialize(board, serializer)
+}
+
+/// Deserializes `Board` from the format produced by [`serialize_board`](./fn.serialize_board.html).
+/// ／[`serialize_board`](./fn.serialize_board.html) の形式から `Board` をデシリアライズする。
+///
+/// Use this with `#[serde(deserialize_with = "cetkaik_core::relative::parse_board")]`.
+/// ／`#[serde(deserialize_with = "cetkaik_core::relative::parse_board")]` として使う。
+///
+/// # Errors
+/// Returns an error if the input is not a 9x9 nested array of pieces and `null`s.
+/// ／入力が駒と `null` からなる 9x9 の入れ子の配列でなければエラーを返す。
+pub fn parse_board<'de, D>(deserializer: D) -> Result<Board, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    serde::de::Deserialize::deserialize(deserializer)
+}
+
 /// Describes the board, the 9x9 squares, in terms of relative coordinates.
 /// ／盤、つまり、9x9のマス目を、相対座標で表す。
 pub type Board = [SingleRow; 9];
@@ -226,7 +511,21 @@ pub type SingleRow = [Option<Piece>; 9];
 
 /// Describes the field, which is defined as a board plus each side's hop1zuo1.
 /// ／フィールドを表す。フィールドとは、盤に両者の手駒を加えたものである。
-#[derive(Debug, Clone, Hash)]
+///
+/// `Field::default()` is the empty field, with nothing on the board and in the hop1zuo1s;
+/// it is *not* the initial configuration, which is given by
+/// [`yhuap_initial_board_where_black_king_points_upward`](./fn.yhuap_initial_board_where_black_king_points_upward.html) and
+/// [`yhuap_initial_board_where_red_king_points_upward`](./fn.yhuap_initial_board_where_red_king_points_upward.html).
+/// ／`Field::default()` は盤にも手駒にも何もない空のフィールドであり、初期配置ではない。
+/// 初期配置は上記の関数で得られる。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+/// let field = Field::default();
+/// assert!(field.current_board.iter().flatten().all(Option::is_none));
+/// assert!(field.hop1zuo1of_upward.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, Hash)]
 pub struct Field {
     /// board／盤
     pub current_board: Board,
@@ -238,6 +537,46 @@ pub struct Field {
     pub hop1zuo1of_downward: Vec<NonTam2PieceDownward>,
 }
 
+/// Returns the initial field, where the red king points upward (i.e. you).
+/// ／初期状態のフィールドを返す。赤王が自分側にある。
+///
+/// The board is [`yhuap_initial_board_where_red_king_points_upward`] and both hop1zuo1s are empty.
+/// ／盤は [`yhuap_initial_board_where_red_king_points_upward`] であり、両者の手駒は空である。
+#[must_use]
+pub fn yhuap_initial_field_where_red_king_points_upward() -> Field {
+    Field {
+        current_board: yhuap_initial_board_where_red_king_points_upward(),
+        hop1zuo1of_upward: vec![],
+        hop1zuo1of_downward: vec![],
+    }
+}
+
+/// Returns the initial field, where the black king points upward (i.e. you).
+/// ／初期状態のフィールドを返す。黒王が自分側にある。
+///
+/// The board is [`yhuap_initial_board_where_black_king_points_upward`] and both hop1zuo1s are empty.
+/// ／盤は [`yhuap_initial_board_where_black_king_points_upward`] であり、両者の手駒は空である。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// let field = yhuap_initial_field_where_black_king_points_upward();
+/// assert_eq!(field.current_board, yhuap_initial_board_where_black_king_points_upward());
+/// assert!(field.hop1zuo1of_upward.is_empty());
+/// assert_eq!(
+///     field.flipped().current_board,
+///     yhuap_initial_field_where_red_king_points_upward().current_board
+/// );
+/// ```
+#[must_use]
+pub const fn yhuap_initial_field_where_black_king_points_upward() -> Field {
+    Field {
+        current_board: yhuap_initial_board_where_black_king_points_upward(),
+        hop1zuo1of_upward: vec![],
+        hop1zuo1of_downward: vec![],
+    }
+}
+
 /// Returns the initial configuration as specified in the y1 huap1 (the standardized rule).
 /// The red king points upward (i.e. you)
 /// ／官定で定められた初期配置を与える。赤王が自分側にある。
@@ -576,10 +915,272 @@ impl Field {
             }
         }
     }
+
+    /// Re-expresses the field as seen by the opponent: the board is rotated with [`rotate_board`](./fn.rotate_board.html) and the two hop1zuo1s are swapped.
+    /// ／相手から見たフィールドに変換する。盤は [`rotate_board`](./fn.rotate_board.html) で回転させ、両者の手駒を入れ替える。
+    ///
+    /// `field.flipped().flipped()` is always identical to `field`.
+    /// ／`field.flipped().flipped()` は常に `field` と同一である。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::relative::*;
+    ///
+    /// let field = Field {
+    ///     current_board: yhuap_initial_board_where_black_king_points_upward(),
+    ///     hop1zuo1of_upward: vec![NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Io }],
+    ///     hop1zuo1of_downward: vec![],
+    /// };
+    /// let flipped = field.flipped();
+    /// assert_eq!(flipped.current_board, yhuap_initial_board_where_red_king_points_upward());
+    /// assert!(flipped.hop1zuo1of_upward.is_empty());
+    /// assert_eq!(
+    ///     flipped.hop1zuo1of_downward,
+    ///     vec![NonTam2PieceDownward { color: Color::Kok1, prof: Profession::Io }]
+    /// );
+    /// assert_eq!(flipped.flipped().current_board, field.current_board);
+    /// ```
+    #[must_use]
+    pub fn flipped(&self) -> Self {
+        Field {
+            current_board: rotate_board(self.current_board),
+            hop1zuo1of_upward: self
+                .hop1zuo1of_downward
+                .iter()
+                .map(|&NonTam2PieceDownward { color, prof }| NonTam2PieceUpward { color, prof })
+                .collect(),
+            hop1zuo1of_downward: self
+                .hop1zuo1of_upward
+                .iter()
+                .map(|&NonTam2PieceUpward { color, prof }| NonTam2PieceDownward { color, prof })
+                .collect(),
+        }
+    }
+
+    /// Returns how many pieces are in the hop1zuo1 of the given side.
+    /// ／指定の陣営の手駒の個数を返す。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::*;
+    /// use cetkaik_core::relative::*;
+    ///
+    /// let field = Field {
+    ///     current_board: yhuap_initial_board_where_black_king_points_upward(),
+    ///     hop1zuo1of_upward: vec![NonTam2PieceUpward { color: Color::Kok1, prof: Profession::Io }],
+    ///     hop1zuo1of_downward: vec![],
+    /// };
+    /// assert_eq!(field.hop1zuo1_len(Side::Upward), 1);
+    /// assert_eq!(field.hop1zuo1_len(Side::Downward), 0);
+    /// ```
+    #[must_use]
+    pub const fn hop1zuo1_len(&self, side: Side) -> usize {
+        match side {
+            Side::Upward => self.hop1zuo1of_upward.len(),
+            Side::Downward => self.hop1zuo1of_downward.len(),
+        }
+    }
+
+    /// Iterates over all 81 squares of the board in row-major order, pairing each with its coordinate.
+    /// ／盤の81マスすべてを行優先の順に、座標と組にして列挙する。
+    ///
+    /// Empty squares are included as `None`.
+    /// ／空きマスも `None` として含まれる。
+    /// # Examples
+    /// ```
+    /// use cetkaik_core::relative::*;
+    ///
+    /// let field = yhuap_initial_field_where_black_king_points_upward();
+    /// let squares: Vec<_> = field.enumerate_board().collect();
+    /// assert_eq!(squares.len(), 81);
+    /// assert_eq!(squares[0], ([0, 0], field.current_board[0][0]));
+    /// assert_eq!(squares[40], ([4, 4], Some(Piece::Tam2)));
+    ///
+    /// let occupied: Vec<String> = field
+    ///     .enumerate_board()
+    ///     .filter_map(|(_, cell)| cell.map(serialize_piece))
+    ///     .collect();
+    /// assert_eq!(occupied.len(), 49);
+    /// ```
+    pub fn enumerate_board(&self) -> impl Iterator<Item = (Coord, Option<Piece>)> + '_ {
+        self.current_board
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, &cell)| ([i, j], cell)))
+    }
+
+    /// Converts into `absolute::Field`, assuming that IA is the lowermost row.
+    /// Shorthand for `perspective::to_absolute_field(self, Perspective::IaIsDownAndPointsUpward)`.
+    /// ／IAが一番下の行であるとして、`absolute::Field` に変換する。
+    #[must_use]
+    pub fn into_absolute_ia_down(self) -> super::absolute::Field {
+        use super::perspective;
+        perspective::to_absolute_field(self, perspective::Perspective::IaIsDownAndPointsUpward)
+    }
+}
+
+/// Builds a [`Field`](./struct.Field.html) one piece at a time, so that the board array need not be written by hand.
+/// ／盤の配列を手で書かずに済むよう、[`Field`](./struct.Field.html) を一つずつ駒を置いて組み立てる。
+///
+/// None of the methods panic; off-board coordinates and the rest of the position are validated only when [`build`](#method.build) is called.
+/// ／どのメソッドも panic しない。盤外の座標や局面の検証は [`build`](#method.build) を呼んだときにのみ行われる。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::relative::*;
+///
+/// let field = FieldBuilder::new()
+///     .place([4, 4], Piece::Tam2)
+///     .place([8, 4], Piece::NonTam2Piece { color: Color::Kok1, prof: Profession::Io, side: Side::Upward })
+///     .add_upward(NonTam2PieceUpward { color: Color::Huok2, prof: Profession::Kauk2 })
+///     .add_downward(NonTam2PieceDownward { color: Color::Kok1, prof: Profession::Kauk2 })
+///     .build()
+///     .unwrap();
+/// assert_eq!(field.current_board[4][4], Some(Piece::Tam2));
+/// assert_eq!(field.hop1zuo1_len(Side::Upward), 1);
+/// assert_eq!(field.hop1zuo1_len(Side::Downward), 1);
+///
+/// assert_eq!(
+///     FieldBuilder::new().place([4, 4], Piece::Tam2).place([9, 0], Piece::Tam2).build().unwrap_err(),
+///     FieldValidationError::OffBoard([9, 0])
+/// );
+/// assert_eq!(FieldBuilder::new().build().unwrap_err(), FieldValidationError::Tam2Count(0));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FieldBuilder {
+    field: Field,
+    first_error: Option<FieldValidationError>,
+}
+
+/// Describes why [`FieldBuilder::build`](./struct.FieldBuilder.html#method.build) rejected the position.
+/// ／[`FieldBuilder::build`](./struct.FieldBuilder.html#method.build) が局面を拒否した理由を表す。
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FieldValidationError {
+    /// A piece was placed outside the board. Holds the first such coordinate.
+    /// ／盤外に駒が置かれた。最初のそのような座標を保持する。
+    OffBoard(Coord),
+
+    /// A piece was placed on a square that already had one. Holds the first such square.
+    /// ／既に駒のあるマスに駒が置かれた。最初のそのようなマスを保持する。
+    SquarePlacedTwice(Coord),
+
+    /// The board does not have exactly one Tam2. Holds the number found.
+    /// ／盤上の皇がちょうど一つではない。見つかった個数を保持する。
+    Tam2Count(usize),
+}
+
+impl std::fmt::Display for FieldValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValidationError::OffBoard(coord) => {
+                write!(
+                    f,
+                    "a piece was placed outside the board at {}",
+                    serialize_coord(*coord)
+                )
+            }
+            FieldValidationError::SquarePlacedTwice(coord) => {
+                write!(
+                    f,
+                    "more than one piece was placed at {}",
+                    serialize_coord(*coord)
+                )
+            }
+            FieldValidationError::Tam2Count(n) => {
+                write!(f, "expected exactly one Tam2 on the board, but found {n}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FieldValidationError {}
+
+impl FieldBuilder {
+    /// Starts from the empty field.
+    /// ／空のフィールドから始める。
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places a piece on the square. An off-board coordinate is recorded and rejected by [`build`](#method.build).
+    /// ／マスに駒を置く。盤外の座標は記録され、[`build`](#method.build) で拒否される。
+    #[must_use]
+    pub fn place(mut self, coord: Coord, piece: Piece) -> Self {
+        let error = if is_valid_coord(coord) {
+            let [row, col] = coord;
+            self.field.current_board[row][col]
+                .replace(piece)
+                .map(|_| FieldValidationError::SquarePlacedTwice(coord))
+        } else {
+            Some(FieldValidationError::OffBoard(coord))
+        };
+        if self.first_error.is_none() {
+            self.first_error = error;
+        }
+        self
+    }
+
+    /// Adds a piece to the hop1zuo1 of `Upward`.
+    /// ／`Upward` の手駒に駒を加える。
+    #[must_use]
+    pub fn add_upward(mut self, piece: NonTam2PieceUpward) -> Self {
+        self.field.hop1zuo1of_upward.push(piece);
+        self
+    }
+
+    /// Adds a piece to the hop1zuo1 of `Downward`.
+    /// ／`Downward` の手駒に駒を加える。
+    #[must_use]
+    pub fn add_downward(mut self, piece: NonTam2PieceDownward) -> Self {
+        self.field.hop1zuo1of_downward.push(piece);
+        self
+    }
+
+    /// Validates and returns the field.
+    /// ／検証した上でフィールドを返す。
+    ///
+    /// # Errors
+    /// Fails if a piece was placed outside the board or on a square more than once, or if the board does not have exactly one Tam2.
+    /// ／盤外や同じマスに二回以上駒が置かれた場合や、盤上の皇がちょうど一つでない場合は失敗する。
+    pub fn build(self) -> Result<Field, FieldValidationError> {
+        if let Some(error) = self.first_error {
+            return Err(error);
+        }
+        let tam2_count = count_tam2(&self.field.current_board);
+        if tam2_count != 1 {
+            return Err(FieldValidationError::Tam2Count(tam2_count));
+        }
+        Ok(self.field)
+    }
 }
 
 /// Rotates a board.
 /// ／盤を180度回転させ、自分陣営と相手陣営を入れ替える。
+///
+/// The center `[4][4]` is the only square that maps to itself. A Tam2 there stays as it is,
+/// but any other piece there still has its side flipped, as every piece on the board does,
+/// since the rotated board is the same position seen by the opponent.
+/// ／中央の `[4][4]` は自分自身に写る唯一のマスである。そこにある皇はそのままだが、
+/// それ以外の駒は盤上の他の駒と同様に陣営が入れ替わる。回転後の盤は同じ局面を相手から見たものだからである。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::relative::*;
+///
+/// let mut board: Board = [[None; 9]; 9];
+/// board[4][4] = Some(Piece::Tam2);
+/// assert_eq!(rotate_board(board)[4][4], Some(Piece::Tam2));
+///
+/// board[4][4] = Some(Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2, side: Side::Upward });
+/// assert_eq!(
+///     rotate_board(board)[4][4],
+///     Some(Piece::NonTam2Piece { color: Color::Huok2, prof: Profession::Kauk2, side: Side::Downward })
+/// );
+///
+/// let mut in_place = board;
+/// rotate_board_in_place(&mut in_place);
+/// assert_eq!(in_place, rotate_board(board));
+/// ```
 #[must_use]
 pub fn rotate_board(b: Board) -> Board {
     let mut ans: Board = [
@@ -601,6 +1202,58 @@ pub fn rotate_board(b: Board) -> Board {
     ans
 }
 
+/// Rotates a board in place, without allocating a new one. Same as [`rotate_board`](./fn.rotate_board.html) otherwise.
+/// ／新たな盤を作らずに、その場で盤を回転させる。それ以外は [`rotate_board`](./fn.rotate_board.html) と同じ。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// let mut board = yhuap_initial_board_where_black_king_points_upward();
+/// rotate_board_in_place(&mut board);
+/// assert_eq!(board, rotate_board(yhuap_initial_board_where_black_king_points_upward()));
+/// ```
+pub fn rotate_board_in_place(b: &mut Board) {
+    // swap each of the first 41 squares (in row-major order) with its image;
+    // the last of them, [4][4], is its own image and thus is only flipped
+    for index in 0..41 {
+        let (i, j) = (index / 9, index % 9);
+        let (i2, j2) = (8 - i, 8 - j);
+        let sq = rotate_piece_or_null(b[i][j]);
+        b[i][j] = rotate_piece_or_null(b[i2][j2]);
+        b[i2][j2] = sq;
+    }
+}
+
+/// Rotates the board by 180° for display, keeping every piece's side.
+/// ／表示のために盤を180度回転させる。駒の陣営はそのまま保つ。
+///
+/// Unlike [`rotate_board`](./fn.rotate_board.html), which re-expresses the board as seen by the opponent
+/// (so that the opponent's pieces become upward), this keeps your upward pieces upward and only moves them to the mirrored squares,
+/// as a "flip board" button in a UI would.
+/// ／[`rotate_board`](./fn.rotate_board.html) は盤を相手から見たものに変換する（相手の駒が上向きになる）のに対し、
+/// この関数は上向きの駒を上向きのまま、点対称なマスに移すだけである。UI の「盤を反転」ボタンのような用途を想定している。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::relative::*;
+///
+/// let board = yhuap_initial_board_where_black_king_points_upward();
+/// let viewed = view_rotated(&board);
+/// assert_eq!(viewed[0][4], board[8][4]);
+/// assert_ne!(viewed, rotate_board(board));
+/// assert_eq!(view_rotated(&viewed), board);
+/// ```
+#[must_use]
+pub fn view_rotated(board: &Board) -> Board {
+    let mut ans: Board = [[None; 9]; 9];
+    for i in 0..9 {
+        for j in 0..9 {
+            ans[i][j] = board[8 - i][8 - j];
+        }
+    }
+    ans
+}
+
 /// Calculates the distance between two points.
 /// The distance is defined as the larger of the difference between either the x or y coordinates.
 /// ／2点間の距離（x座標の差およびy座標の差のうち小さくない方）を計算する。
@@ -611,7 +1264,7 @@ pub fn rotate_board(b: Board) -> Board {
 /// assert_eq!(3, distance([4,5], [1,2]));
 /// assert_eq!(3, distance([1,2], [4,5]));
 /// ```
-/// 
+///
 /// # Panics
 /// Panics if the `Coord` is so invalid that it does not fit in `i32`.
 /// ／`Coord` に入っている座標が `i32` に収まらないほど巨大であれば panic する。
@@ -626,3 +1279,154 @@ pub fn distance(a: Coord, b: Coord) -> i32 {
 
     x_distance.max(y_distance)
 }
+
+/// Calculates the distance between two points without panicking.
+/// ／2点間の距離を、panic せずに計算する。
+///
+/// Works just like [`distance`](./fn.distance.html), but returns `None` if the `Coord` does not fit in `i32`.
+/// ／[`distance`](./fn.distance.html) と同様だが、`Coord` が `i32` に収まらないときは `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+/// assert_eq!(Some(3), checked_distance([4,5], [1,2]));
+/// assert_eq!(None, checked_distance([4,5], [usize::MAX, 2]));
+/// ```
+#[must_use]
+pub fn checked_distance(a: Coord, b: Coord) -> Option<i32> {
+    use std::convert::TryFrom;
+    let [x1, y1] = a;
+    let [x2, y2] = b;
+
+    let x_distance = i32::try_from(x1)
+        .ok()?
+        .checked_sub(i32::try_from(x2).ok()?)?
+        .checked_abs()?;
+    let y_distance = i32::try_from(y1)
+        .ok()?
+        .checked_sub(i32::try_from(y2).ok()?)?
+        .checked_abs()?;
+
+    Some(x_distance.max(y_distance))
+}
+
+/// Moves the coordinate by `dr` rows and `dc` columns. Returns `None` if the result falls outside the board.
+/// ／座標を `dr` 行、`dc` 列だけずらす。盤外に出るなら `None` を返す。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+/// assert_eq!(step([4, 5], -1, 2), Some([3, 7]));
+/// assert_eq!(step([0, 5], -1, 0), None);
+/// assert_eq!(step([4, 8], 0, 1), None);
+/// ```
+#[must_use]
+pub fn step(coord: Coord, dr: i32, dc: i32) -> Option<Coord> {
+    use std::convert::TryFrom;
+    let [row, col] = coord;
+    let new_row = usize::try_from(i32::try_from(row).ok()?.checked_add(dr)?).ok()?;
+    let new_col = usize::try_from(i32::try_from(col).ok()?.checked_add(dc)?).ok()?;
+    if new_row <= 8 && new_col <= 8 {
+        Some([new_row, new_col])
+    } else {
+        None
+    }
+}
+
+/// Lists the squares adjacent to the coordinate, including the diagonal ones.
+/// ／隣接するマス（斜めを含む）を列挙する。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+/// assert_eq!(neighbors([4, 4]).len(), 8);
+/// assert_eq!(neighbors([0, 4]).len(), 5);
+/// assert_eq!(neighbors([8, 8]), vec![[7, 7], [7, 8], [8, 7]]);
+/// ```
+#[must_use]
+pub fn neighbors(coord: Coord) -> Vec<Coord> {
+    let mut ans = vec![];
+    for dr in -1..=1 {
+        for dc in -1..=1 {
+            if dr == 0 && dc == 0 {
+                continue;
+            }
+            if let Some(c) = step(coord, dr, dc) {
+                ans.push(c);
+            }
+        }
+    }
+    ans
+}
+
+/// Counts the Tam2s on the board.
+/// ／盤上の皇の個数を数える。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// let mut board = yhuap_initial_board_where_black_king_points_upward();
+/// assert_eq!(count_tam2(&board), 1);
+/// board[4][4] = None;
+/// assert_eq!(count_tam2(&board), 0);
+/// ```
+#[must_use]
+pub fn count_tam2(board: &Board) -> usize {
+    board
+        .iter()
+        .flatten()
+        .filter(|sq| matches!(sq, Some(Piece::Tam2)))
+        .count()
+}
+
+/// Checks that there is exactly one Tam2 on the board, as there should be throughout the game.
+/// ／盤上にちょうど一つの皇があるかを調べる。対局中は常にそうであるべきである。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// let mut board = yhuap_initial_board_where_black_king_points_upward();
+/// assert!(has_unique_tam2(&board));
+/// board[0][0] = Some(Piece::Tam2);
+/// assert!(!has_unique_tam2(&board));
+/// ```
+#[must_use]
+pub fn has_unique_tam2(board: &Board) -> bool {
+    count_tam2(board) == 1
+}
+
+/// Counts the pieces on the board that belong to the given side. Tam2 belongs to neither side and is not counted.
+/// ／盤上にある、指定の陣営に属する駒の個数を数える。皇はどちらの陣営にも属さないので数えない。
+/// # Examples
+/// ```
+/// use cetkaik_core::relative::*;
+///
+/// let board = yhuap_initial_board_where_black_king_points_upward();
+/// assert_eq!(count_by_side(&board, Side::Upward), 24);
+/// assert_eq!(count_by_side(&board, Side::Downward), 24);
+/// ```
+#[must_use]
+pub fn count_by_side(board: &Board, side: Side) -> usize {
+    board
+        .iter()
+        .flatten()
+        .filter(|sq| sq.is_some_and(|p| p.has_side(side)))
+        .count()
+}
+
+/// Counts the pieces on the board that have the given color. Tam2 has neither color and is not counted.
+/// ／盤上にある、指定の色の駒の個数を数える。皇は赤でも黒でもないので数えない。
+/// # Examples
+/// ```
+/// use cetkaik_core::*;
+/// use cetkaik_core::relative::*;
+///
+/// let board = yhuap_initial_board_where_black_king_points_upward();
+/// assert_eq!(count_by_color(&board, Color::Kok1), 24);
+/// assert_eq!(count_by_color(&board, Color::Huok2), 24);
+/// ```
+#[must_use]
+pub fn count_by_color(board: &Board, color: Color) -> usize {
+    board
+        .iter()
+        .flatten()
+        .filter(|sq| sq.is_some_and(|p| p.has_color(color)))
+        .count()
+}